@@ -0,0 +1,257 @@
+use std::{
+    alloc::{GlobalAlloc, Layout},
+    fmt::Display,
+    fs,
+    sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering},
+};
+
+use anyhow::bail;
+use mimalloc::MiMalloc;
+use serde::{Deserialize, Serialize};
+
+/// What the binaries allocate with, which they have to install themselves since a library mustn't:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: Allocator = Allocator::new();
+/// ```
+///
+/// mimalloc makes a small difference in solver speed (day 12's especially).
+pub type Allocator = Counting<MiMalloc>;
+
+// The unit tests count allocations too
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: Allocator = Allocator::new();
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static COUNT: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+/// Signed, since what was allocated before counting started can be freed after
+static LIVE: AtomicI64 = AtomicI64::new(0);
+static PEAK: AtomicI64 = AtomicI64::new(0);
+
+/// Another allocator, keeping count of what goes through it while counting is on
+pub struct Counting<A>(pub A);
+
+impl Allocator {
+    pub const fn new() -> Self {
+        Counting(MiMalloc)
+    }
+}
+
+impl Default for Allocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What a phase (parsing, solving...) took out of the allocator
+///
+/// Reallocations count as allocations of their new size. Only Rust's allocations go through the counter,
+/// HiGHS allocates behind its back, which is what `resident` is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Allocations {
+    pub count: u64,
+    pub bytes: u64,
+    /// The most that was live at once, on top of what already was when the phase started
+    pub peak: u64,
+    /// The process' peak resident set during the phase, where the OS can tell (Linux)
+    pub resident: Option<u64>,
+}
+
+/// Counting costs an atomic or two per allocation, so it's off unless asked for
+///
+/// Turning it on fails if the allocator isn't the [`Counting`] one, when there'd be nothing to count with.
+pub fn set_enabled(enabled: bool) -> anyhow::Result<()> {
+    ENABLED.store(enabled, Ordering::Relaxed);
+
+    if enabled {
+        let before = COUNT.load(Ordering::SeqCst);
+
+        drop(std::hint::black_box(Box::new(0u64)));
+
+        if COUNT.load(Ordering::SeqCst) == before {
+            ENABLED.store(false, Ordering::Relaxed);
+
+            bail!("allocations can't be counted, the global allocator isn't allocations::Counting");
+        }
+    }
+
+    Ok(())
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Runs `f`, counting what it allocates if counting is on
+///
+/// Other threads' allocations are counted too, which is what's wanted for the solvers that fan out over rayon,
+/// but it means phases shouldn't be measured at the same time as each other.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    if !enabled() {
+        return (f(), None);
+    }
+
+    let resetting = reset_peak_resident();
+    let (count, bytes, live) = (COUNT.load(Ordering::SeqCst), BYTES.load(Ordering::SeqCst), LIVE.load(Ordering::SeqCst));
+
+    PEAK.store(live, Ordering::SeqCst);
+
+    let result = f();
+
+    let allocations = Allocations {
+        count: COUNT.load(Ordering::SeqCst) - count,
+        bytes: BYTES.load(Ordering::SeqCst) - bytes,
+        peak: (PEAK.load(Ordering::SeqCst) - live).max(0) as u64,
+        resident: if resetting { peak_resident() } else { None },
+    };
+
+    (result, Some(allocations))
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for Counting<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { self.0.alloc(layout) };
+
+        if !ptr.is_null() {
+            allocated(layout.size(), 0);
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { self.0.alloc_zeroed(layout) };
+
+        if !ptr.is_null() {
+            allocated(layout.size(), 0);
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { self.0.dealloc(ptr, layout) };
+
+        if ENABLED.load(Ordering::Relaxed) {
+            LIVE.fetch_sub(layout.size() as i64, Ordering::Relaxed);
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { self.0.realloc(ptr, layout, new_size) };
+
+        if !new.is_null() {
+            allocated(new_size, layout.size());
+        }
+
+        new
+    }
+}
+
+/// `size` bytes came into use in place of `replaced`
+fn allocated(size: usize, replaced: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    COUNT.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size as u64, Ordering::Relaxed);
+
+    let live = LIVE.fetch_add(size as i64 - replaced as i64, Ordering::Relaxed) + size as i64 - replaced as i64;
+
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+/// Makes VmHWM start over from the current resident set, which Linux does when 5 is written to clear_refs
+fn reset_peak_resident() -> bool {
+    fs::write("/proc/self/clear_refs", "5").is_ok()
+}
+
+fn peak_resident() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let kilobytes = status.lines().find_map(|line| line.strip_prefix("VmHWM:"))?.trim().strip_suffix("kB")?;
+
+    Some(kilobytes.trim().parse::<u64>().ok()? << 10)
+}
+
+impl Allocations {
+    /// The worst of two runs, field by field
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            count: self.count.max(other.count),
+            bytes: self.bytes.max(other.bytes),
+            peak: self.peak.max(other.peak),
+            resident: self.resident.max(other.resident),
+        }
+    }
+}
+
+/// Like "1520 / 3.2M / 1.1M", allocations, bytes and peak, then the resident peak in brackets if it's known
+impl Display for Allocations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} / {} / {}", self.count, Bytes(self.bytes), Bytes(self.peak))?;
+
+        if let Some(resident) = self.resident {
+            write!(f, " ({})", Bytes(resident))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A byte count with the same K/M/G suffixes `--memory` takes
+struct Bytes(u64);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 4] = ["K", "M", "G", "T"];
+
+        if self.0 < 1 << 10 {
+            return write!(f, "{}B", self.0);
+        }
+
+        let (mut value, mut unit) = (self.0 as f64 / 1024.0, 0);
+
+        while value >= 1024.0 && unit + 1 < UNITS.len() {
+            value /= 1024.0;
+            unit += 1;
+        }
+
+        write!(f, "{value:.1}{}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counting_a_phase() {
+        set_enabled(true).unwrap();
+
+        let (sum, allocations) = measure(|| {
+            let numbers: Vec<u64> = (0..1 << 16).collect();
+
+            numbers.iter().sum::<u64>()
+        });
+        let allocations = allocations.unwrap();
+
+        assert_eq!(sum, (1 << 15) * ((1 << 16) - 1));
+        // Other tests allocate at the same time, so these are only lower bounds
+        assert!(allocations.count >= 1);
+        assert!(allocations.bytes >= 8 << 16);
+        assert!(allocations.peak >= 8 << 16);
+    }
+
+    #[test]
+    fn human_sizes() {
+        let allocations = Allocations { count: 1520, bytes: 3 << 20, peak: 1536, resident: Some(5 << 30) };
+
+        assert_eq!(allocations.to_string(), "1520 / 3.0M / 1.5K (5.0G)");
+        assert_eq!(Bytes(1000).to_string(), "1000B");
+        assert_eq!(Allocations { count: 1, ..allocations }.max(allocations).count, 1520);
+    }
+}
@@ -0,0 +1,202 @@
+use std::{fmt::Display, sync::LazyLock};
+
+use anyhow::Context as _;
+
+use crate::{parse::{self, ParseError}, runner::{Day, Part}};
+
+/// What we know about one part's answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recorded {
+    pub day: u8,
+    pub part: Part,
+    /// `None` while the part hasn't been solved
+    pub accepted: Option<String>,
+    pub wrong: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub recorded: Vec<Recorded>,
+}
+
+/// How an answer holds up against the record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Accepted,
+    /// Already submitted and turned down
+    KnownWrong,
+    /// Differs from the accepted answer
+    Changed { accepted: String },
+    Unrecorded,
+}
+
+/// One part that went through `aoc verify`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+static CHECKED_IN: LazyLock<Answers> = LazyLock::new(|| {
+    Answers::parse(include_str!("answers.txt")).expect("src/answers.txt should parse")
+});
+
+impl Answers {
+    /// The answers in src/answers.txt
+    pub fn checked_in() -> &'static Answers {
+        &CHECKED_IN
+    }
+
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut recorded = vec![];
+
+        for mut line in parse::lines(s) {
+            if line.is_empty() || line.rest().starts_with('#') {
+                continue;
+            }
+
+            let day = line.number()?;
+            line.expect(" ")?;
+            let part = match line.char_of("12", "part 1 or 2")? {
+                '1' => Part::One,
+                _ => Part::Two,
+            };
+            line.expect(" ")?;
+
+            let accepted = match line.take_until(' ') {
+                "" => return Err(line.error("an answer")),
+                "?" => None,
+                answer => Some(answer.to_owned()),
+            };
+
+            let mut wrong = vec![];
+
+            if line.eat(" wrong") {
+                while line.eat(" ") {
+                    match line.take_until(' ') {
+                        "" => return Err(line.error("a wrong answer")),
+                        answer => wrong.push(answer.to_owned()),
+                    }
+                }
+
+                if wrong.is_empty() {
+                    return Err(line.error("at least one wrong answer"));
+                }
+            }
+
+            line.end()?;
+
+            recorded.push(Recorded { day, part, accepted, wrong });
+        }
+
+        Ok(Self { recorded })
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&Recorded> {
+        self.recorded.iter().find(|r| r.day == day && r.part == part)
+    }
+
+    /// The answers Eric turned down for a part, for solvers that want to steer clear of them
+    pub fn wrong(&self, day: u8, part: Part) -> &[String] {
+        self.get(day, part).map_or(&[], |r| &r.wrong)
+    }
+
+    pub fn judge(&self, day: u8, part: Part, answer: &str) -> Verdict {
+        let Some(recorded) = self.get(day, part) else {
+            return Verdict::Unrecorded;
+        };
+
+        match &recorded.accepted {
+            Some(accepted) if accepted == answer => Verdict::Accepted,
+            _ if recorded.wrong.iter().any(|w| w == answer) => Verdict::KnownWrong,
+            Some(accepted) => Verdict::Changed { accepted: accepted.clone() },
+            None => Verdict::Unrecorded,
+        }
+    }
+
+    /// Solves both parts of a day and judges them against the record
+    pub fn verify(&self, day: &Day, input: &str) -> anyhow::Result<Vec<Check>> {
+        Part::ALL.iter().map(|&part| {
+            let answer = day.run(part, input, None)?;
+            let verdict = self.judge(day.day, part, &answer);
+
+            Ok(Check { day: day.day, part, answer, verdict })
+        }).collect::<anyhow::Result<_>>().with_context(|| format!("verifying day {}", day.day))
+    }
+}
+
+impl Check {
+    pub fn is_ok(&self) -> bool {
+        matches!(self.verdict, Verdict::Accepted | Verdict::Unrecorded)
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {} part {}: {} ", self.day, self.part, self.answer)?;
+
+        match &self.verdict {
+            Verdict::Accepted => f.write_str("ok"),
+            Verdict::KnownWrong => f.write_str("WRONG (we've been told off for this one before)"),
+            Verdict::Changed { accepted } => write!(f, "CHANGED (accepted answer is {accepted})"),
+            Verdict::Unrecorded => f.write_str("(no accepted answer on record)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{input, runner::find_day};
+
+    const ANSWERS: &str = "# comment
+1 1 3
+1 2 ? wrong 5 7
+
+9 2 42 wrong 40 41";
+
+    #[test]
+    fn parse_and_judge() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(answers.recorded.len(), 3);
+        assert_eq!(answers.wrong(9, Part::Two), ["40", "41"]);
+        assert!(answers.wrong(1, Part::One).is_empty());
+
+        assert_eq!(answers.judge(1, Part::One, "3"), Verdict::Accepted);
+        assert_eq!(answers.judge(1, Part::One, "4"), Verdict::Changed { accepted: "3".into() });
+        assert_eq!(answers.judge(1, Part::Two, "5"), Verdict::KnownWrong);
+        assert_eq!(answers.judge(1, Part::Two, "6"), Verdict::Unrecorded);
+        assert_eq!(answers.judge(9, Part::Two, "41"), Verdict::KnownWrong);
+        assert_eq!(answers.judge(2, Part::One, "1"), Verdict::Unrecorded);
+
+        assert_eq!(Answers::parse("1 3 4").unwrap_err().to_string(), "line 1, column 3: expected part 1 or 2, found \"3\"");
+        assert!(Answers::parse("1 1 4 wrong").is_err());
+    }
+
+    #[test]
+    fn checked_in_answers_cover_every_part() {
+        let answers = Answers::checked_in();
+
+        for day in crate::runner::DAYS {
+            for part in Part::ALL {
+                assert!(answers.get(day.day, part).is_some(), "day {} part {part} has no record", day.day);
+            }
+        }
+    }
+
+    // The quick ones; `aoc verify` goes through the rest
+    #[test]
+    fn quick_days_still_agree() {
+        for day in [1, 2, 4, 5, 6, 7, 11] {
+            let day = find_day(day).unwrap();
+            let input = input::load(day.day, None).unwrap();
+
+            for check in Answers::checked_in().verify(day, &input).unwrap() {
+                assert_eq!(check.verdict, Verdict::Accepted, "{check}");
+            }
+        }
+    }
+}
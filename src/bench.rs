@@ -0,0 +1,275 @@
+use std::{fmt::Display, hint::black_box, time::{Duration, Instant}};
+
+use anyhow::{Context as _, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::{allocations::{self, Allocations}, runner::Part, solution::{DEFAULT, Solution}};
+
+/// [`measure`] for one day, with the solution type erased
+pub type Measure = fn(Part, &str, &[String], usize) -> anyhow::Result<Vec<Measurement>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    #[serde(with = "nanos")]
+    pub min: Duration,
+    #[serde(with = "nanos")]
+    pub median: Duration,
+    #[serde(with = "nanos")]
+    pub max: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub part: Part,
+    pub variant: String,
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+    /// What parsing and solving allocated, the worst run of each, if allocations were being counted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Memory>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Memory {
+    pub parse: Allocations,
+    pub solve: Allocations,
+}
+
+/// How a measurement's median solve time moved since the baseline
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: Part,
+    pub variant: String,
+    pub before: Duration,
+    pub after: Duration,
+    pub regressed: bool,
+}
+
+impl Stats {
+    pub fn of(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "can't make stats out of no runs");
+
+        samples.sort();
+
+        Self {
+            min: samples[0],
+            median: samples[(samples.len() - 1) / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Times parsing and solving a part `runs` times over, once for each of the requested solvers
+///
+/// `only` holds variant names: empty means the part's own solver, "all" means it and every variant.
+pub fn measure<S: Solution>(part: Part, input: &str, only: &[String], runs: usize) -> anyhow::Result<Vec<Measurement>> {
+    let variants = S::variants(part);
+    let all = only.iter().any(|name| name == "all");
+
+    if let Some(unknown) = only.iter().find(|name| *name != "all" && *name != DEFAULT && !variants.iter().any(|v| v.name == name.as_str())) {
+        let known: Vec<_> = variants.iter().map(|v| v.name).collect();
+
+        bail!("part {part} has no variant {unknown:?}, it has {DEFAULT:?} and {known:?}");
+    }
+
+    let mut measurements = vec![];
+
+    if all || only.is_empty() || only.iter().any(|name| name == DEFAULT) {
+        let solve = |solution: &S| match part {
+            Part::One => { black_box(solution.part1()?); Ok(()) },
+            Part::Two => { black_box(solution.part2()?); Ok(()) },
+        };
+
+        measurements.push(time(part, DEFAULT, input, runs, solve)?);
+    }
+
+    for variant in variants.iter().filter(|v| all || only.iter().any(|name| name == v.name)) {
+        measurements.push(time(part, variant.name, input, runs, |solution: &S| { black_box((variant.items)(solution)); Ok(()) })?);
+    }
+
+    Ok(measurements)
+}
+
+fn time<S: Solution>(part: Part, variant: &str, input: &str, runs: usize, solve: impl Fn(&S) -> anyhow::Result<()>) -> anyhow::Result<Measurement> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut solving = Vec::with_capacity(runs);
+    let mut memory: Option<Memory> = None;
+
+    for _ in 0..runs {
+        // Timed inside so that setting up the counting isn't
+        let ((solution, elapsed), parse_allocations) = allocations::measure(|| timed(|| S::parse(input)));
+        parse.push(elapsed);
+        let solution = solution.context("malformed input")?;
+
+        let ((solved, elapsed), solve_allocations) = allocations::measure(|| timed(|| solve(&solution)));
+        solving.push(elapsed);
+        solved?;
+
+        if let (Some(parse), Some(solve)) = (parse_allocations, solve_allocations) {
+            memory = Some(match memory {
+                Some(worst) => Memory { parse: worst.parse.max(parse), solve: worst.solve.max(solve) },
+                None => Memory { parse, solve },
+            });
+        }
+    }
+
+    Ok(Measurement {
+        // The runner knows which day this is
+        day: 0,
+        part,
+        variant: variant.to_owned(),
+        runs,
+        parse: Stats::of(parse),
+        solve: Stats::of(solving),
+        memory,
+    })
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+
+    (result, start.elapsed())
+}
+
+/// Lines the measurements up against a baseline by day, part and variant;
+/// anything more than `tolerance` percent slower counts as a regression
+pub fn compare(current: &[Measurement], baseline: &[Measurement], tolerance: f64) -> Vec<Comparison> {
+    current.iter().filter_map(|m| {
+        let old = baseline.iter().find(|b| b.day == m.day && b.part == m.part && b.variant == m.variant)?;
+        let limit = old.solve.median.as_secs_f64() * (1.0 + tolerance / 100.0);
+
+        Some(Comparison {
+            day: m.day,
+            part: m.part,
+            variant: m.variant.clone(),
+            before: old.solve.median,
+            after: m.solve.median,
+            regressed: m.solve.median.as_secs_f64() > limit,
+        })
+    }).collect()
+}
+
+impl Comparison {
+    /// Percentage change of the median solve time, positive when it got slower
+    pub fn change(&self) -> f64 {
+        (self.after.as_secs_f64() / self.before.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0) * 100.0
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.2?} / {:.2?} / {:.2?}", self.min, self.median, self.max)
+    }
+}
+
+pub fn print_table(measurements: &[Measurement], comparisons: &[Comparison]) {
+    let counted = measurements.iter().any(|m| m.memory.is_some());
+
+    print!("{:>3} {:>4}  {:<20} {:>4}  {:<32} {:<32}", "day", "part", "variant", "runs", "parse min / median / max", "solve min / median / max");
+
+    if counted {
+        print!(" {:<34} {:<34}", "parse allocs / bytes / peak (rss)", "solve allocs / bytes / peak (rss)");
+    }
+
+    println!();
+
+    for m in measurements {
+        print!("{:>3} {:>4}  {:<20} {:>4}  {:<32} {:<32}", m.day, m.part, m.variant, m.runs, m.parse.to_string(), m.solve.to_string());
+
+        if let Some(memory) = &m.memory {
+            print!(" {:<34} {:<34}", memory.parse.to_string(), memory.solve.to_string());
+        } else if counted {
+            print!(" {:<34} {:<34}", "", "");
+        }
+
+        if let Some(c) = comparisons.iter().find(|c| c.day == m.day && c.part == m.part && c.variant == m.variant) {
+            print!(" {:+.1}% vs {:.2?}", c.change(), c.before);
+
+            if c.regressed {
+                print!(" REGRESSION");
+            }
+        }
+
+        println!();
+    }
+}
+
+/// Durations go in and out of JSON as whole nanoseconds
+pub(crate) mod nanos {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos().try_into().unwrap_or(u64::MAX))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    fn measurement(variant: &str, median: u64) -> Measurement {
+        let stats = Stats { min: ms(median), median: ms(median), max: ms(median) };
+
+        Measurement { day: 2, part: Part::Two, variant: variant.into(), runs: 1, parse: stats, solve: stats, memory: None }
+    }
+
+    #[test]
+    fn stats() {
+        assert_eq!(Stats::of(vec![ms(5), ms(1), ms(3)]), Stats { min: ms(1), median: ms(3), max: ms(5) });
+        assert_eq!(Stats::of(vec![ms(4), ms(2)]).median, ms(2));
+    }
+
+    #[test]
+    fn regressions_against_a_baseline() {
+        let baseline = [measurement("repeat", 100), measurement("pux", 10)];
+        let current = [measurement("repeat", 105), measurement("pux", 20), measurement(DEFAULT, 10)];
+
+        let comparisons = compare(&current, &baseline, 10.0);
+
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].regressed);
+        assert!(comparisons[1].regressed);
+        assert_eq!(comparisons[1].change().round(), 100.0);
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let measurements = vec![measurement("pux", 10)];
+        let json = serde_json::to_string(&measurements).unwrap();
+
+        assert!(json.contains("\"part\":2"));
+        assert!(json.contains("\"median\":10000000"));
+        assert!(!json.contains("memory"));
+        assert_eq!(serde_json::from_str::<Vec<Measurement>>(&json).unwrap(), measurements);
+    }
+
+    #[test]
+    fn variants_to_measure() {
+        let input = crate::input::load(2, None).unwrap();
+
+        let names = |only: &[&str]| {
+            let only: Vec<_> = only.iter().map(|s| s.to_string()).collect();
+
+            measure::<crate::day2::Elfilter>(Part::Two, &input, &only, 1).map(|ms| ms.into_iter().map(|m| m.variant).collect::<Vec<_>>())
+        };
+
+        assert_eq!(names(&[]).unwrap(), [DEFAULT]);
+        assert_eq!(names(&["all"]).unwrap(), [DEFAULT, "repeat", "pux"]);
+        assert_eq!(names(&["pux"]).unwrap(), ["pux"]);
+        assert!(names(&["simple"]).is_err());
+    }
+}
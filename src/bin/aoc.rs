@@ -0,0 +1,8 @@
+use aoc2025::allocations::Allocator;
+
+#[global_allocator]
+static ALLOCATOR: Allocator = Allocator::new();
+
+fn main() -> std::process::ExitCode {
+    aoc2025::cli::aoc_main()
+}
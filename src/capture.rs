@@ -0,0 +1,57 @@
+use std::{cell::RefCell, sync::{Arc, Mutex}, thread::LocalKey};
+
+/// Things noted while some code runs, like the items it gave up on or the warnings it logged
+///
+/// Each kind keeps the capture running on a thread in a thread local [`Slot`] of its own. Clones share the
+/// list, so the capture can be handed to the threads working for the one that started it; those don't know
+/// about it themselves.
+#[derive(Debug, Clone, Default)]
+pub struct Capture<T>(Option<Arc<Mutex<Vec<T>>>>);
+
+/// Where a thread keeps the capture it's running, if any
+pub type Slot<T> = RefCell<Capture<T>>;
+
+impl<T> Capture<T> {
+    /// A fresh capture, with nothing noted yet
+    pub fn new() -> Self {
+        Self(Some(Arc::new(Mutex::new(vec![]))))
+    }
+
+    /// Not capturing, anything noted goes nowhere
+    pub const fn none() -> Self {
+        Self(None)
+    }
+
+    /// The capture running on this thread, or none
+    pub fn current(slot: &'static LocalKey<Slot<T>>) -> Self {
+        slot.with_borrow(|capture| Self(capture.0.clone()))
+    }
+
+    /// Runs `f` with this as the capture running on this thread, putting back whatever was there after,
+    /// even if `f` panics
+    pub fn within<R>(&self, slot: &'static LocalKey<Slot<T>>, f: impl FnOnce() -> R) -> R {
+        struct Restore<T: 'static>(&'static LocalKey<Slot<T>>, Capture<T>);
+
+        impl<T> Drop for Restore<T> {
+            fn drop(&mut self) {
+                self.0.set(std::mem::replace(&mut self.1, Capture::none()));
+            }
+        }
+
+        let _restore = Restore(slot, slot.replace(Self(self.0.clone())));
+
+        f()
+    }
+
+    /// Notes `item`, if this is capturing at all
+    pub fn push(&self, item: T) {
+        if let Some(items) = &self.0 {
+            items.lock().unwrap().push(item);
+        }
+    }
+
+    /// Everything noted so far, in the order it was
+    pub fn take(&self) -> Vec<T> {
+        self.0.as_ref().map_or_else(Vec::new, |items| std::mem::take(&mut *items.lock().unwrap()))
+    }
+}
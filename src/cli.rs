@@ -0,0 +1,255 @@
+use std::{num::NonZeroUsize, path::{Path, PathBuf}, process::ExitCode, time::Duration};
+
+use clap::{ArgAction, Args, Parser, Subcommand};
+
+use crate::{deadline, logger, recorder::{Export, ImageFormat}, runner::{self, BenchOptions, Format, Part, Selection}, scaffold, workers};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2025, all days in one place")]
+pub struct Aoc {
+    #[command(flatten)]
+    pub verbosity: Verbosity,
+    #[command(flatten)]
+    pub resources: Resources,
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// How much the solvers get to say on stderr, on top of `AOC_LOG`
+#[derive(Args)]
+pub struct Verbosity {
+    /// More diagnostics (-v for debug, -vv for trace)
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+    /// Fewer diagnostics (-q for warnings only, -qq for errors, -qqq for silence)
+    #[arg(short, long, action = ArgAction::Count, global = true, conflicts_with = "verbose")]
+    pub quiet: u8,
+}
+
+impl Verbosity {
+    pub fn init(&self) {
+        logger::init(self.verbose as i8 - self.quiet as i8);
+    }
+}
+
+/// How much of the machine the solvers get, on top of `AOC_THREADS` and `AOC_MEMORY`
+#[derive(Args)]
+pub struct Resources {
+    /// Worker threads for the parallel solvers (one per core if not given)
+    #[arg(long, global = true)]
+    pub threads: Option<NonZeroUsize>,
+    /// Memory the heavy models may take between them, like 512M or 16G (20G if not given)
+    #[arg(long, value_name = "BYTES", value_parser = workers::parse_bytes, global = true)]
+    pub memory: Option<u64>,
+}
+
+impl Resources {
+    pub fn init(&self) {
+        workers::init(self.threads.map(NonZeroUsize::get), self.memory);
+    }
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Run a day (or all of them), optionally just one part
+    Run {
+        /// Day number, or "all"
+        selection: Selection,
+        /// 1 or 2; runs both if left out
+        part: Option<Part>,
+        /// Read the input from this file instead of src/inputs, or from stdin with "-"
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Run every variant of each part and report where they disagree
+        #[arg(long)]
+        cross_check: bool,
+        /// Answer with this variant instead; with --cross-check, only compare these (comma separated)
+        #[arg(long, value_delimiter = ',')]
+        variant: Vec<String>,
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+        /// Give up on any one item (region, machine, bank...) after this many seconds and report it as unresolved
+        #[arg(long, value_name = "SECONDS", value_parser = seconds)]
+        timeout: Option<Duration>,
+        /// Show how each answer came about, step by step (day 1)
+        #[arg(long, conflicts_with = "cross_check")]
+        trace: bool,
+    },
+    /// Time parsing and solving, a few runs over
+    Bench {
+        /// Day number, or "all"
+        selection: Selection,
+        /// 1 or 2; times both if left out
+        part: Option<Part>,
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// How many times to run each solver
+        #[arg(long, short = 'n', default_value_t = 5, value_parser = clap::value_parser!(u16).range(1..))]
+        runs: u16,
+        /// Which solvers to time (comma separated): "default", variant names, or "all"
+        #[arg(long, value_delimiter = ',')]
+        variant: Vec<String>,
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+        /// Save the results as JSON, to compare against later
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Results saved earlier with --save
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Percent slower than the baseline that counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        tolerance: f64,
+        /// Count allocations, bytes and peak memory for parsing and solving too, which slows them down a little
+        #[arg(long)]
+        allocations: bool,
+    },
+    /// Print a random input for a day
+    Gen {
+        day: u8,
+        /// The same seed (and size) always makes the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Roughly how many lines, machines, regions... to make
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+    },
+    /// Record a simulation step by step (days 4 and 7) and write it out as images
+    Record {
+        day: u8,
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// The GIF to write, or the directory to put the numbered frames in
+        #[arg(long)]
+        out: PathBuf,
+        #[arg(long, value_enum, default_value_t)]
+        format: ImageFormat,
+        /// Pixels per cell, each way
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u16).range(1..))]
+        scale: u16,
+        /// Hundredths of a second per frame in the GIF
+        #[arg(long, default_value_t = 10)]
+        delay: u16,
+    },
+    /// Start a new day from the src/day0.rs template, wired up and with an empty input and example to fill in
+    New {
+        day: u8,
+        /// The day's type (Day<N> if not given)
+        #[arg(long)]
+        name: Option<String>,
+    },
+    /// Solve every part again and check the answers against src/answers.txt
+    Verify {
+        /// Day number, or "all"
+        #[arg(default_value = "all")]
+        selection: Selection,
+    },
+    /// List the days that have solutions
+    List,
+}
+
+/// What every `dayN` binary takes
+#[derive(Parser)]
+pub struct DayArgs {
+    /// 1 or 2
+    pub part: Part,
+    /// Read the input from this file instead of src/inputs, or from stdin with "-"
+    #[arg(long)]
+    pub input: Option<PathBuf>,
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+    /// Show how the answer came about, step by step
+    #[arg(long)]
+    pub trace: bool,
+    #[command(flatten)]
+    pub verbosity: Verbosity,
+    #[command(flatten)]
+    pub resources: Resources,
+}
+
+pub fn aoc_main() -> ExitCode {
+    let aoc = Aoc::parse();
+
+    aoc.verbosity.init();
+    aoc.resources.init();
+
+    if let Command::Run { timeout, .. } = aoc.command {
+        deadline::set_per_item(timeout);
+    }
+
+    let result = match aoc.command {
+        Command::Run { selection, part, input, cross_check: true, variant, .. } => {
+            runner::cross_check(selection, part, input.as_deref(), &variant)
+        },
+        Command::Run { variant, .. } if variant.len() > 1 => {
+            Err(anyhow::anyhow!("pick one --variant to answer with, or compare them with --cross-check"))
+        },
+        Command::Run { selection, part, input, variant, format, trace, .. } => {
+            runner::run(selection, part, input.as_deref(), variant.first().map(String::as_str), format, trace)
+        },
+        Command::Bench { selection, part, input, runs, variant, format, save, baseline, tolerance, allocations } => {
+            let options = BenchOptions {
+                runs: runs.into(),
+                variants: &variant,
+                format,
+                save: save.as_deref(),
+                baseline: baseline.as_deref(),
+                tolerance,
+                allocations,
+            };
+
+            runner::bench(selection, part, input.as_deref(), options)
+        },
+        Command::Gen { day, seed, size } => runner::find_day(day).and_then(|day| runner::generate(day, seed, size)).map(|input| print!("{input}")),
+        Command::Record { day, input, out, format, scale, delay } => runner::find_day(day).and_then(|day| {
+            runner::record(day, input.as_deref(), &out, Export { format, scale: scale.into(), delay })
+        }),
+        Command::New { day, name } => {
+            let name = name.unwrap_or_else(|| format!("Day{day}"));
+
+            scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), day, &name).map(|changed| {
+                for path in changed {
+                    println!("{}", path.display());
+                }
+            })
+        },
+        Command::Verify { selection } => runner::verify(selection),
+        Command::List => {
+            runner::list();
+
+            Ok(())
+        },
+    };
+
+    report(result)
+}
+
+fn seconds(s: &str) -> Result<Duration, String> {
+    s.parse::<f64>().ok()
+        .filter(|seconds| *seconds > 0.0)
+        // Fails on NaN, infinities and anything too long for a Duration
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or_else(|| format!("expected a positive number of seconds, got {s:?}"))
+}
+
+pub fn day_main(day: u8) -> ExitCode {
+    let args = DayArgs::parse();
+
+    args.verbosity.init();
+    args.resources.init();
+
+    let result = runner::run(Selection::Day(day), Some(args.part), args.input.as_deref(), None, args.format, args.trace);
+
+    report(result)
+}
+
+fn report(result: anyhow::Result<()>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+
+            ExitCode::FAILURE
+        }
+    }
+}
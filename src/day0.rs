@@ -0,0 +1,62 @@
+use rand::rngs::StdRng;
+
+use crate::{parse, solution::Solution};
+
+pub struct Template {
+    #[allow(unused)]
+    lines: Vec<String>
+}
+
+impl Template {
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let lines = parse::lines(s).map(|line| line.rest().to_owned()).collect();
+
+        Ok(Self { lines })
+    }
+}
+
+impl Solution for Template {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Template::parse(input)
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        todo!();
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        todo!();
+    }
+
+    /// Nothing yet, which at least parses
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let _ = (rng, size);
+
+        String::new()
+    }
+}
+
+#[cfg(test)]
+#[allow(unused)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day0/example.txt");
+
+    #[test]
+    fn example() {
+        let puzzle = Template::parse(EXAMPLE).unwrap();
+
+        // assert_eq!(puzzle.part1().unwrap(), 0);
+    }
+
+    #[test]
+    fn example_part2() {
+        let puzzle = Template::parse(EXAMPLE).unwrap();
+
+        // assert_eq!(puzzle.part2().unwrap(), 0);
+    }
+}
@@ -0,0 +1,715 @@
+use std::fmt::Display;
+
+use anyhow::{Context as _, bail};
+use rand::{Rng as _, rngs::StdRng};
+
+use crate::{parse::{self, Cursor, ParseError}, runner::Part, solution::Solution};
+
+/// A dial of `size` notches numbered from 0, counting how often it stops on or goes past `target`
+pub struct Unsafe {
+    size: u64,
+    position: u64,
+    target: u64
+}
+
+pub struct Rotations {
+    rotations: Vec<Rotation>
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rotation {
+    pub direction: Direction,
+    pub turns: u64
+}
+
+/// Several dials, each turned on its own and each with its own size and target
+pub struct Lock {
+    dials: Vec<Unsafe>
+}
+
+/// Rotations for a [`Lock`], one per line as `2:L68` for dial 2 (from 0), or just `L68` for dial 0
+pub struct LockRotations {
+    rotations: Vec<(usize, Rotation)>
+}
+
+/// How often a dial, or all of them together, got to the target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Counts {
+    /// Rotations that stopped on it
+    pub landings: u64,
+    /// Every time it got there, stopping or going past, which u64 turns can take past a u64
+    pub slides: u128,
+}
+
+/// What turning a lock came to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockCounts {
+    /// In the lock's order
+    pub dials: Vec<Counts>,
+    /// Rotations after which every dial was on its target at once
+    pub opened: u64,
+}
+
+/// The counts for every position the dial could start from, see [`Rotations::every_start`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Starts {
+    pub landings: Histogram<u64>,
+    pub slides: Histogram<u128>,
+}
+
+/// A count per start position
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram<T> {
+    counts: Vec<T>
+}
+
+/// What one rotation did to the dial
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event<'a> {
+    /// 0-based, in input order
+    pub index: usize,
+    pub rotation: &'a Rotation,
+    pub start: u64,
+    pub end: u64,
+    /// Times it got to the target, stopping on it included
+    pub passes: u64,
+    /// The step it first got there on, 1 being the notch next to `start`
+    first_pass: u64,
+    size: u64,
+}
+
+/// The rotations played one by one on a dial, see [`Rotations::events`]
+pub struct Events<'a> {
+    safe: Unsafe,
+    rotations: std::iter::Enumerate<std::slice::Iter<'a, Rotation>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right
+}
+
+impl Unsafe {
+    /// The puzzle's safe: 100 notches, starting at 50
+    pub fn new() -> Self {
+        Self {
+            size: 100,
+            position: 50,
+            target: 0
+        }
+    }
+
+    /// A dial with any number of notches, starting at notch `start`, and with 0 as the target
+    pub fn with_dial(size: u64, start: u64) -> anyhow::Result<Self> {
+        if size == 0 {
+            bail!("a dial needs at least one notch");
+        }
+
+        if start >= size {
+            bail!("there's no notch {start} on a dial of {size}");
+        }
+
+        Ok(Self { size, position: start, target: 0 })
+    }
+
+    /// The same dial, counting the times it gets to `target` instead of 0
+    pub fn targeting(self, target: u64) -> anyhow::Result<Self> {
+        if target >= self.size {
+            bail!("there's no notch {target} to aim at on a dial of {}", self.size);
+        }
+
+        Ok(Self { target, ..self })
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    pub fn target(&self) -> u64 {
+        self.target
+    }
+
+    pub fn count_zero_landings(&mut self, rotations: &Rotations) -> u64 {
+        let mut landings = 0;
+
+        for rotation in rotations.rotations.iter() {
+            self.rotate(rotation);
+
+            if self.position == self.target {
+                landings += 1;
+            }
+        }
+
+        landings
+    }
+
+
+    /// Each rotation passes at most a u64's worth, so the total only fits in a u128
+    pub fn count_zero_slides(&mut self, rotations: &Rotations) -> u128 {
+        let mut slides: u128 = 0;
+
+        for rotation in rotations.rotations.iter() {
+            slides += self.rotate(rotation) as u128;
+        }
+
+        slides
+    }
+
+    /// Turns the dial, returning how many times it got to the target on the way, stopping on it included
+    /// but not starting from it
+    ///
+    /// Worked out in one go rather than notch by notch, so turns in the quintillions take as long as any other.
+    pub fn rotate(&mut self, rotation: &Rotation) -> u64 {
+        let passes = match self.notches_to_target(&rotation.direction) {
+            first if rotation.turns >= first => (rotation.turns - first) / self.size + 1,
+            _ => 0,
+        };
+
+        let turns = (rotation.turns % self.size) as i128;
+        let moved = self.position as i128 + if rotation.direction == Direction::Left { -turns } else { turns };
+
+        self.position = moved.rem_euclid(self.size as i128) as u64;
+
+        passes
+    }
+
+    /// How far the dial has to turn that way to get to the target, a whole turn if it's already there
+    fn notches_to_target(&self, direction: &Direction) -> u64 {
+        let (from, to) = match direction {
+            Direction::Right => (self.position as i128, self.target as i128),
+            Direction::Left => (self.target as i128, self.position as i128),
+        };
+
+        match (to - from).rem_euclid(self.size as i128) as u64 {
+            0 => self.size,
+            distance => distance,
+        }
+    }
+}
+
+impl Lock {
+    pub fn new(dials: Vec<Unsafe>) -> anyhow::Result<Self> {
+        if dials.is_empty() {
+            bail!("a lock needs at least one dial");
+        }
+
+        Ok(Self { dials })
+    }
+
+    /// `count` of the puzzle's dials
+    pub fn of_safes(count: usize) -> anyhow::Result<Self> {
+        Self::new((0..count).map(|_| Unsafe::new()).collect())
+    }
+
+    pub fn dials(&self) -> &[Unsafe] {
+        &self.dials
+    }
+
+    /// Turns the dials, counting for each of them as it goes
+    ///
+    /// Rotations for a dial the lock doesn't have are refused before anything's turned.
+    pub fn turn(&mut self, rotations: &LockRotations) -> anyhow::Result<LockCounts> {
+        if let Some((index, (dial, rotation))) = rotations.rotations.iter().enumerate().find(|(_, (dial, _))| *dial >= self.dials.len()) {
+            bail!("rotation {} ({dial}:{rotation}) is for dial {dial}, the lock only has {}", index + 1, self.dials.len());
+        }
+
+        let mut counts = LockCounts { dials: vec![Counts::default(); self.dials.len()], opened: 0 };
+
+        for (dial, rotation) in &rotations.rotations {
+            let safe = &mut self.dials[*dial];
+            let count = &mut counts.dials[*dial];
+
+            count.slides += safe.rotate(rotation) as u128;
+
+            if safe.position == safe.target {
+                count.landings += 1;
+
+                if self.dials.iter().all(|safe| safe.position == safe.target) {
+                    counts.opened += 1;
+                }
+            }
+        }
+
+        Ok(counts)
+    }
+}
+
+impl LockCounts {
+    /// All the dials' counts added up
+    pub fn combined(&self) -> Counts {
+        self.dials.iter().fold(Counts::default(), |total, dial| Counts {
+            landings: total.landings + dial.landings,
+            slides: total.slides + dial.slides,
+        })
+    }
+}
+
+impl LockRotations {
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let rotations = parse::lines(s).map(|mut line| {
+            let dial = if line.peek().is_some_and(|c| c.is_ascii_digit()) {
+                let dial = line.number()?;
+
+                line.expect(":")?;
+
+                dial
+            } else {
+                0
+            };
+
+            Ok((dial, Rotation::parse(&mut line)?))
+        }).collect::<Result<_, ParseError>>()?;
+
+        Ok(Self { rotations })
+    }
+}
+
+impl Default for Unsafe {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rotations {
+    /// What each rotation does in turn, starting from `safe`
+    pub fn events(&self, safe: Unsafe) -> Events<'_> {
+        Events { safe, rotations: self.rotations.iter().enumerate() }
+    }
+
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let lines = parse::lines(s).map(|mut line| Rotation::parse(&mut line)).collect::<Result<_, ParseError>>()?;
+
+        Ok(Self {
+            rotations: lines
+        })
+    }
+}
+
+impl Rotations {
+    /// The landings and slides from each of the `size` start positions, aiming at `target`
+    ///
+    /// Starting `s` notches further round shifts every position along by `s`, so rather than playing the
+    /// rotations from each start it's enough to know where they go relative to the start. A rotation lands on
+    /// the target from exactly one start, and passes it a whole number of times more from a run of starts
+    /// next to each other, which a difference array adds up for all of them at once. That's linear in the
+    /// rotations plus the size, though it keeps a count per notch, so dials only go up to 16M notches.
+    pub fn every_start(&self, size: u64, target: u64) -> anyhow::Result<Starts> {
+        if size == 0 {
+            bail!("a dial needs at least one notch");
+        }
+
+        if target >= size {
+            bail!("there's no notch {target} to aim at on a dial of {size}");
+        }
+
+        if size > MAX_STARTS {
+            bail!("a dial of {size} is too big to count every start of, {MAX_STARTS} notches is the most");
+        }
+
+        let notches = size as usize;
+        let mut landings = counts(notches)?;
+        // +1 from a start up to -1 after the last one, for the passes in the last partial turn
+        let mut partial = counts::<i64>(notches + 1)?;
+        let mut whole_turns: u128 = 0;
+        // Where the dial is relative to where it started, mod size
+        let mut moved: u64 = 0;
+        let size128 = size as i128;
+
+        // The start that puts the dial `relative` notches from it on `notch`
+        let start_for = |notch: i128, relative: u64| (notch - relative as i128).rem_euclid(size128) as usize;
+
+        for rotation in &self.rotations {
+            let rest = rotation.turns % size;
+
+            whole_turns += (rotation.turns / size) as u128;
+
+            if rest > 0 {
+                // The notches left over after the whole turns reach the target from the `rest` positions just
+                // before it going right, or just after it going left
+                let first = match rotation.direction {
+                    Direction::Right => start_for(target as i128 - rest as i128, moved),
+                    Direction::Left => start_for(target as i128 + 1, moved),
+                };
+                let end = first + rest as usize;
+
+                partial[first] += 1;
+
+                if end <= notches {
+                    partial[end] -= 1;
+                } else {
+                    partial[notches] -= 1;
+                    partial[0] += 1;
+                    partial[end - notches] -= 1;
+                }
+            }
+
+            let turned = if rotation.direction == Direction::Left { -(rest as i128) } else { rest as i128 };
+
+            moved = (moved as i128 + turned).rem_euclid(size128) as u64;
+            landings[start_for(target as i128, moved)] += 1;
+        }
+
+        let mut running = 0;
+        let mut slides = counts(notches)?;
+
+        for (slide, change) in slides.iter_mut().zip(&partial) {
+            running += change;
+
+            *slide = whole_turns + running as u128;
+        }
+
+        Ok(Starts { landings: Histogram { counts: landings }, slides: Histogram { counts: slides } })
+    }
+}
+
+/// The biggest dial [`Rotations::every_start`] takes, which needs 32 bytes a notch
+const MAX_STARTS: u64 = 1 << 24;
+
+/// `len` zeroes, or an error rather than an abort if there isn't the memory for them
+fn counts<T: Clone + Default>(len: usize) -> anyhow::Result<Vec<T>> {
+    let mut counts = Vec::new();
+
+    counts.try_reserve_exact(len).with_context(|| format!("there isn't the memory to count {len} starts"))?;
+    counts.resize(len, T::default());
+
+    Ok(counts)
+}
+
+impl<T: Copy + Ord> Histogram<T> {
+    /// Indexed by start position
+    pub fn counts(&self) -> &[T] {
+        &self.counts
+    }
+
+    /// The start with the highest count, the lowest such start if there's a tie, and the count
+    pub fn best(&self) -> (u64, T) {
+        let (start, count) = self.counts.iter().enumerate().rev().max_by_key(|(_, count)| **count).expect("a dial has notches");
+
+        (start as u64, *count)
+    }
+}
+
+impl<'a> Iterator for Events<'a> {
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Event<'a>> {
+        let (index, rotation) = self.rotations.next()?;
+        let start = self.safe.position;
+        let first_pass = self.safe.notches_to_target(&rotation.direction);
+        let passes = self.safe.rotate(rotation);
+
+        Some(Event { index, rotation, start, end: self.safe.position, passes, first_pass, size: self.safe.size })
+    }
+}
+
+impl Event<'_> {
+    /// The steps into the rotation at which the dial got to the target, in order
+    pub fn pass_steps(&self) -> impl Iterator<Item = u64> + use<> {
+        let (first, every) = (self.first_pass, self.size);
+
+        (0..self.passes).map(move |i| first + i * every)
+    }
+}
+
+/// How many pass steps a trace line lists before it gives up, turns can go round a lot of times
+const STEPS_SHOWN: usize = 5;
+
+/// Like "line 3: R48 from 52 to 0, 1 pass at step 48"
+impl Display for Event<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {} from {} to {}", self.index + 1, self.rotation, self.start, self.end)?;
+
+        if self.passes == 0 {
+            return Ok(());
+        }
+
+        let steps: Vec<_> = self.pass_steps().take(STEPS_SHOWN).map(|step| step.to_string()).collect();
+        let more = if self.passes > STEPS_SHOWN as u64 { ", ..." } else { "" };
+
+        match self.passes {
+            1 => write!(f, ", 1 pass at step {}", steps[0]),
+            passes => write!(f, ", {passes} passes at steps {}{more}", steps.join(", ")),
+        }
+    }
+}
+
+impl Rotation {
+    /// The rest of the line, like `L68`
+    fn parse(line: &mut Cursor) -> Result<Self, ParseError> {
+        let direction = match line.char_of("LR", "L or R")? {
+            'L' => Direction::Left,
+            _ => Direction::Right,
+        };
+        let turns: u64 = line.number()?;
+
+        line.end()?;
+
+        Ok(Rotation { direction, turns })
+    }
+}
+
+impl Display for Rotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let direction = match self.direction {
+            Direction::Left => 'L',
+            Direction::Right => 'R',
+        };
+
+        write!(f, "{direction}{}", self.turns)
+    }
+}
+
+impl Solution for Rotations {
+    type Part1 = u64;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Rotations::parse(input)
+    }
+
+    fn part1(&self) -> anyhow::Result<u64> {
+        Ok(Unsafe::new().count_zero_landings(self))
+    }
+
+    fn part2(&self) -> anyhow::Result<u128> {
+        Ok(Unsafe::new().count_zero_slides(self))
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        (0..size).map(|_| {
+            let direction = if rng.random() { 'L' } else { 'R' };
+
+            format!("{direction}{}\n", rng.random_range(1..1000))
+        }).collect()
+    }
+
+    /// Both parts are counted off the same rotations
+    fn trace(&self, part: Part) -> Vec<String> {
+        let _ = part;
+
+        self.events(Unsafe::new()).map(|event| event.to_string()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day1/example.txt");
+
+    #[test]
+    fn example() {
+        let mut safe = Unsafe::new();
+        let rotations = Rotations::parse(EXAMPLE).unwrap();
+
+        assert_eq!(safe.count_zero_landings(&rotations), 3);
+    }
+
+    #[test]
+    fn example_part2() {
+        let mut safe = Unsafe::new();
+        let rotations = Rotations::parse(EXAMPLE).unwrap();
+
+        assert_eq!(safe.count_zero_slides(&rotations), 6);
+    }
+
+    #[test]
+    fn other_dials() {
+        let rotations = Rotations::parse("R4\nL2\nR11").unwrap();
+
+        // 4 goes past 3, 2 back past it again, and 11 goes round twice to stop on it
+        let mut safe = Unsafe::with_dial(5, 0).unwrap().targeting(3).unwrap();
+        assert_eq!(safe.count_zero_slides(&rotations), 5);
+        assert_eq!(safe.position(), 3);
+
+        let mut safe = Unsafe::with_dial(5, 0).unwrap().targeting(3).unwrap();
+        assert_eq!(safe.count_zero_landings(&rotations), 1);
+
+        let example = Rotations::parse(EXAMPLE).unwrap();
+        let mut big = Unsafe::with_dial(5000, 4950).unwrap().targeting(4900).unwrap();
+        // Without wrapping round at 100, L99 no longer stops on it and L82 no longer goes past
+        assert_eq!(big.count_zero_slides(&example), 4);
+
+        assert!(Unsafe::with_dial(0, 0).is_err());
+        assert!(Unsafe::with_dial(100, 100).is_err());
+        assert!(Unsafe::with_dial(100, 0).unwrap().targeting(100).is_err());
+    }
+
+    /// Rotations from proptest's (whether it's left, turns) pairs
+    fn rotations_of(turns: &[(bool, u64)]) -> Rotations {
+        Rotations {
+            rotations: turns.iter().map(|&(left, turns)| Rotation {
+                direction: if left { Direction::Left } else { Direction::Right },
+                turns,
+            }).collect(),
+        }
+    }
+
+    /// Notch by notch, which can't get the arithmetic wrong
+    fn simulate(size: u64, start: u64, target: u64, rotations: &[(bool, u64)]) -> (u64, u64, u64) {
+        let (mut position, mut landings, mut slides) = (start, 0, 0);
+
+        for &(left, turns) in rotations {
+            for _ in 0..turns {
+                position = if left { (position + size - 1) % size } else { (position + 1) % size };
+
+                if position == target {
+                    slides += 1;
+                }
+            }
+
+            if position == target {
+                landings += 1;
+            }
+        }
+
+        (position, landings, slides)
+    }
+
+    proptest! {
+        #[test]
+        fn closed_form_agrees_with_simulating(
+            (size, start, target) in (1..60u64).prop_flat_map(|size| (Just(size), 0..size, 0..size)),
+            rotations in prop::collection::vec((any::<bool>(), 0..400u64), 0..20),
+        ) {
+            let parsed = rotations_of(&rotations);
+            let dial = || Unsafe::with_dial(size, start).unwrap().targeting(target).unwrap();
+
+            let (position, landings, slides) = simulate(size, start, target, &rotations);
+            let mut safe = dial();
+
+            prop_assert_eq!(safe.count_zero_slides(&parsed), slides as u128);
+            prop_assert_eq!(safe.position(), position);
+            prop_assert_eq!(dial().count_zero_landings(&parsed), landings);
+        }
+    }
+
+    #[test]
+    fn enormous_turns() {
+        let rotations = Rotations::parse("R18446744073709551615\nL18446744073709551615").unwrap();
+        let mut safe = Unsafe::new();
+
+        // 50 notches to the first 0, then every 100; u64::MAX ends in 15
+        assert_eq!(safe.rotate(&rotations.rotations[0]), (u64::MAX - 50) / 100 + 1);
+        assert_eq!(safe.position(), 65);
+        assert_eq!(safe.rotate(&rotations.rotations[1]), (u64::MAX - 65) / 100 + 1);
+        assert_eq!(safe.position(), 50);
+
+        let mut huge = Unsafe::with_dial(u64::MAX, u64::MAX - 1).unwrap();
+        assert_eq!(huge.count_zero_slides(&rotations), 2);
+        assert_eq!(huge.position(), u64::MAX - 1);
+
+        // Each one fits in a u64, 200 of them add up to more than one can hold
+        let many = Rotations::parse(&"R18446744073709551615\n".repeat(200)).unwrap();
+        let total: u128 = many.events(Unsafe::new()).map(|event| event.passes as u128).sum();
+
+        assert!(total > u64::MAX as u128);
+        assert_eq!(Unsafe::new().count_zero_slides(&many), total);
+        assert_eq!(many.every_start(100, 0).unwrap().slides.counts()[50], total);
+    }
+
+    #[test]
+    fn example_events() {
+        let rotations = Rotations::parse(EXAMPLE).unwrap();
+        let events: Vec<_> = rotations.events(Unsafe::new()).collect();
+
+        assert_eq!(events.len(), 10);
+        assert_eq!(events.iter().map(|e| e.passes).sum::<u64>(), 6);
+        assert_eq!(events.iter().filter(|e| e.end == 0).count(), 3);
+        assert!(events.windows(2).all(|w| w[0].end == w[1].start));
+
+        let trace: Vec<_> = events.iter().take(3).map(Event::to_string).collect();
+
+        assert_eq!(trace, [
+            "line 1: L68 from 50 to 82, 1 pass at step 50",
+            "line 2: L30 from 82 to 52",
+            "line 3: R48 from 52 to 0, 1 pass at step 48",
+        ]);
+
+        let around = Rotations::parse("R1000").unwrap();
+        let event = around.events(Unsafe::new()).next().unwrap();
+
+        assert_eq!(event.to_string(), "line 1: R1000 from 50 to 50, 10 passes at steps 50, 150, 250, 350, 450, ...");
+        assert_eq!(event.pass_steps().last(), Some(950));
+    }
+
+    #[test]
+    fn locks() {
+        let rotations = LockRotations::parse("L50\n1:R3\n1:L13\n2:R1000\n0:R100").unwrap();
+        let small = Unsafe::with_dial(10, 3).unwrap().targeting(6).unwrap();
+        let mut lock = Lock::new(vec![Unsafe::new(), small, Unsafe::new()]).unwrap();
+
+        let counts = lock.turn(&rotations).unwrap();
+
+        assert_eq!(counts.dials, [
+            Counts { landings: 2, slides: 2 },
+            // 3 up to 6, then all the way round past it and on to 3
+            Counts { landings: 1, slides: 2 },
+            Counts { landings: 0, slides: 10 },
+        ]);
+        assert_eq!(counts.combined(), Counts { landings: 3, slides: 14 });
+        assert_eq!(counts.opened, 0);
+        assert_eq!(lock.dials().iter().map(Unsafe::position).collect::<Vec<_>>(), [0, 3, 50]);
+
+        // Every dial on 0 after the last one
+        let mut lock = Lock::of_safes(2).unwrap();
+        let counts = lock.turn(&LockRotations::parse("R50\n1:L150\n0:L100").unwrap()).unwrap();
+
+        assert_eq!(counts.opened, 2);
+
+        let err = Lock::of_safes(2).unwrap().turn(&LockRotations::parse("L1\n2:R5").unwrap()).unwrap_err();
+
+        assert_eq!(err.to_string(), "rotation 2 (2:R5) is for dial 2, the lock only has 2");
+        assert!(LockRotations::parse("1;L5").is_err());
+        assert!(Lock::new(vec![]).is_err());
+    }
+
+    #[test]
+    fn example_from_every_start() {
+        let rotations = Rotations::parse(EXAMPLE).unwrap();
+        let starts = rotations.every_start(100, 0).unwrap();
+
+        assert_eq!(starts.landings.counts()[50], 3);
+        assert_eq!(starts.slides.counts()[50], 6);
+        assert_eq!(starts.landings.counts().iter().sum::<u64>(), 10);
+        // No other start stops on 0 as often, the example was made for 50
+        assert_eq!(starts.landings.best(), (50, 3));
+        assert_eq!(starts.landings.counts().iter().filter(|&&count| count == 3).count(), 1);
+
+        assert!(rotations.every_start(100, 100).is_err());
+        assert!(rotations.every_start(u64::MAX, 0).is_err());
+        assert!(rotations.every_start(MAX_STARTS + 1, 0).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn every_start_agrees_with_each(
+            (size, target) in (1..40u64).prop_flat_map(|size| (Just(size), 0..size)),
+            rotations in prop::collection::vec((any::<bool>(), 0..200u64), 0..15),
+        ) {
+            let parsed = rotations_of(&rotations);
+            let starts = parsed.every_start(size, target).unwrap();
+
+            for start in 0..size {
+                let dial = || Unsafe::with_dial(size, start).unwrap().targeting(target).unwrap();
+
+                prop_assert_eq!(starts.landings.counts()[start as usize], dial().count_zero_landings(&parsed));
+                prop_assert_eq!(starts.slides.counts()[start as usize], dial().count_zero_slides(&parsed));
+            }
+        }
+    }
+
+    #[test]
+    fn malformed() {
+        let err = Rotations::parse("L68\nL30\nU48").err().unwrap();
+
+        assert_eq!(err.to_string(), "line 3, column 1: expected L or R, found \"U48\"");
+    }
+}
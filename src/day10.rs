@@ -0,0 +1,793 @@
+use std::fmt::Display;
+
+use anyhow::Context as _;
+use rayon::iter::{IndexedParallelIterator as _, IntoParallelRefIterator as _, ParallelIterator as _};
+use rand::{Rng as _, rngs::StdRng, seq::index};
+#[cfg(feature = "highs")]
+use good_lp::{
+    Expression, Solution as _, SolverModel, default_solver, variable, variables
+};
+
+use crate::{deadline::{Deadline, Timeout, Unresolved}, logger::Warnings, memo::Memo, parse::{self, Cursor, ParseError}, progress::Progress, runner::Part, solution::{Solution, Variant}};
+
+#[derive(Debug)]
+pub struct Factory {
+    machines: Vec<Machine>
+}
+
+#[derive(Debug)]
+pub struct Machine {
+    target_indicator_lights: Vec<bool>,
+    buttons: Vec<Vec<usize>>,
+    joltage_requirements: Vec<usize>,
+}
+
+impl Factory {
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let machines = parse::lines(s).map(Machine::from_cursor).collect::<Result<_, ParseError>>()?;
+
+        Ok(Self {
+            machines
+        })
+    }
+
+    pub fn sum_of_fewest_presses(&self) -> anyhow::Result<usize> {
+        self.machines.iter()
+            .enumerate()
+            .map(|(index, m)| {
+                let presses = m.fewest_presses().with_context(|| format!("line {}: no presses light up {m}", index + 1))?;
+
+                log::debug!("{presses}");
+
+                Ok(presses)
+            })
+            .sum()
+    }
+
+    /// Like the others, None if some machine's joltages can't be reached
+    pub fn sum_of_fewest_joltages(&self) -> Option<usize> {
+        let progress = Progress::new("machines", self.machines.len());
+
+        self.machines.par_iter()
+            .map(|m| m.fewest_presses_for_joltage_dumb(&Deadline::never()).ok().flatten())
+            .inspect(|_| progress.tick())
+            .collect::<Option<Vec<_>>>()
+            .map(|presses| presses.iter().sum())
+    }
+
+    pub fn sum_of_fewest_joltages_less_dumb(&self) -> Option<usize> {
+        let progress = Progress::new("machines", self.machines.len());
+
+        self.machines.par_iter()
+            .map(|m| m.fewest_presses_for_joltage_less_dumb())
+            .inspect(|_| progress.tick())
+            .collect::<Option<Vec<_>>>()
+            .map(|presses| presses.iter().sum())
+    }
+
+    pub fn sum_of_fewest_joltages_more_dumb(&self) -> Option<usize> {
+        self.machines.iter()
+            .enumerate()
+            .map(|(index, m)| (index, m.fewest_presses_for_joltage_possibly_more_dumb()))
+            .map(|(_, count)| count)
+            .sum()
+
+    }
+}
+
+impl Machine {
+    pub fn from_line(s: &str) -> Result<Self, ParseError> {
+        Self::from_cursor(Cursor::new(1, s))
+    }
+
+    fn from_cursor(mut s: Cursor) -> Result<Self, ParseError> {
+        // First non-trivial parsing problem
+        // but I still got it first try B)
+        s.expect("[")?;
+
+        let mut target_indicator_lights = vec![];
+
+        while !s.eat("]") {
+            target_indicator_lights.push(s.char_of("#.", "a light (# or .) or ]")? == '#');
+        }
+
+        let lights = target_indicator_lights.len();
+        let mut buttons = vec![];
+
+        s.expect(" (")?;
+
+        loop {
+            let mut numbers = vec![];
+
+            loop {
+                let at = s.clone();
+                let light: usize = s.number()?;
+
+                if light >= lights {
+                    return Err(at.error(format_args!("a light index below {lights}")));
+                }
+
+                numbers.push(light);
+
+                if !s.eat(",") {
+                    break;
+                }
+            }
+
+            buttons.push(numbers);
+
+            if s.eat(") {") {
+                break;
+            }
+
+            s.expect(") (")?;
+        }
+
+        let mut joltage_requirements = vec![];
+
+        loop {
+            joltage_requirements.push(s.number()?);
+
+            if s.eat("}") {
+                break;
+            }
+
+            if joltage_requirements.len() == lights {
+                return Err(s.error(format_args!("}} after {lights} joltages, one per light")));
+            }
+
+            s.expect(",")?;
+        }
+
+        if joltage_requirements.len() != lights {
+            return Err(s.error(format_args!("{lights} joltages, one per light")));
+        }
+
+        s.end()?;
+
+        Ok(Self {
+            target_indicator_lights,
+            buttons,
+            joltage_requirements,
+        })
+    }
+
+    /// None if no presses get the lights to the target
+    pub fn fewest_presses(&self) -> Option<usize> {
+        let mut lights = vec![false; self.target_indicator_lights.len()];
+
+        // Pressing a button twice undoes it, so there's no need to press more than each of them once
+        for count in 0..=self.buttons.len() {
+            let mut indices = vec![0usize; count];
+
+            loop {
+                lights.fill(false);
+
+                for bindex in indices.iter() {
+                    for lindex in self.buttons[*bindex].iter() {
+                        lights[*lindex] = !lights[*lindex];
+                    }
+                }
+
+                // eprintln!("{indices:?} {lights:?}");
+
+                if lights == self.target_indicator_lights {
+                    log::trace!("{indices:?}");
+
+                    return Some(count);
+                }
+
+                let Some((index, _)) = indices.iter().enumerate().rev().find(|(_, count)| **count != self.buttons.len() - 1) else {
+                    break;
+                };
+
+                indices[index] += 1;
+
+                indices.iter_mut().skip(index + 1).for_each(|c| *c = 0);
+            }
+        }
+
+        None
+    }
+
+    /// Takes hours on some machines, so it checks `deadline` as it goes; None if no presses reach the joltages
+    pub fn fewest_presses_for_joltage_dumb(&self, deadline: &Deadline) -> Result<Option<usize>, Timeout> {
+        let mut joltages = vec![0; self.joltage_requirements.len()];
+        // Every press adds at least one to some joltage, so there's no point pressing more often than they add up to
+        let most = self.joltage_requirements.iter().sum();
+
+        for count in 0..=most {
+            let mut indices = vec![0usize; count];
+
+            for tries in 0u64.. {
+                if tries.is_multiple_of(4096) {
+                    deadline.check()?;
+                }
+
+                joltages.fill(0);
+
+                for bindex in indices.iter() {
+                    for lindex in self.buttons[*bindex].iter() {
+                        joltages[*lindex] += 1;
+                    }
+                }
+
+                if joltages == self.joltage_requirements {
+                    // eprintln!("{indices:?}");
+
+                    return Ok(Some(count));
+                }
+
+                let Some((index, _)) = indices.iter().enumerate().rev().find(|(_, count)| **count != self.buttons.len() - 1) else {
+                    break;
+                };
+
+                indices[index] += 1;
+
+                indices.iter_mut().skip(index + 1).for_each(|c| *c = 0);
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub fn fewest_presses_for_joltage_less_dumb(&self) -> Option<usize> {
+        // Let's be less retarded; working backwards is pretty much the first step in most aoc optimization problems
+        // The joltage requirements can only be reached by pressing some of the buttons,
+        // so we can count which ones are needed to reach the requirements
+        // Alternatively, we can be greedy and start by maximizing the buttons that produce the most joltages
+
+        fn search(
+            buttons: &[(usize, &Vec<usize>)],
+            start: usize,
+            remaining: &mut [usize],
+            current: &mut Vec<usize>,
+            best: &mut Option<usize>,
+        ) {
+            if remaining.iter().all(|&x| x == 0) {
+                let presses = current.len();
+
+                if best.is_none_or(|b| presses < b) {
+                    *best = Some(presses);
+                }
+
+                return;
+            }
+
+            if let Some(b) = *best && current.len() >= b {
+                return;
+            }
+
+            'buttons_loop:
+            for button_index in start..buttons.len() {
+                let (btn_index, idxs) = buttons[button_index];
+
+                if !idxs.iter().all(|&idx| remaining[idx] > 0) {
+                    continue;
+                }
+
+                for (idx_idx, &idx) in idxs.iter().enumerate() {
+                    let Some(newb) = remaining[idx].checked_sub(1) else {
+                        // Undo damage
+                        for &idx in idxs.iter().take(idx_idx) {
+                            remaining[idx] += 1;
+                        }
+
+                        continue 'buttons_loop;
+                    };
+
+                    remaining[idx] = newb;
+                }
+
+                current.push(btn_index);
+
+                search(buttons, button_index, remaining, current, best);
+
+                current.pop();
+                for &idx in idxs {
+                    remaining[idx] += 1;
+                }
+            }
+        }
+
+        let mut buttons: Vec<_> = self.buttons
+            .iter()
+            .enumerate()
+            .collect();
+
+        buttons.sort_by_key(|(_, idxs)| std::cmp::Reverse(idxs.len()));
+
+        let mut best = None;
+        let mut current = Vec::new();
+        let mut remaining = self.joltage_requirements.clone();
+
+        search(&buttons, 0, &mut remaining, &mut current, &mut best);
+
+        best
+    }
+
+    pub fn fewest_presses_for_joltage_possibly_more_dumb(&self) -> Option<usize> {
+        // I had this idea while taking a shit: what if we represent the joltages as an n-dimensional position
+        // And we can just do a graph search where each step can bring us closer to that position?
+        // Really this means implementing fucking dijkstra again but I'm done with that and I brought in
+        // a lib to use A* with
+
+        #[derive(PartialEq, Eq, Hash, Clone, Debug)]
+        struct Position {
+            remaining_joltages: Vec<usize>,
+            cost: usize
+        }
+
+        impl Position {
+            fn successors(&self, machine: &Machine) -> Vec<(Position, usize)> {
+                let mut result = Vec::new();
+
+                'outer:
+                for affected in machine.buttons.iter() {
+                    let mut next = self.remaining_joltages.clone();
+
+                    for &i in affected {
+                        if next[i] == 0 {
+                            continue 'outer;
+                        }
+
+                        next[i] -= 1;
+                    }
+
+                    result.push((
+                        Position {
+                            cost: self.cost - affected.len(),
+                            remaining_joltages: next,
+                        },
+                        // All the costs are 1 (as is the heuristic)
+                        // Idk if this fucks over A*
+                        1,
+                    ));
+                }
+
+                // eprintln!("{result:?}");
+
+                // panic!();
+
+                result
+            }
+
+            fn heuristic(&self) -> usize {
+                // In the worst case, you'd press a button for every single joltage remaining, wouldn't you?
+                self.cost
+            }
+        }
+
+        let result = pathfinding::directed::astar::astar(
+        &Position {
+                remaining_joltages: self.joltage_requirements.clone(),
+                cost: self.joltage_requirements.iter().sum()
+            },
+            |p| p.successors(self),
+            |p| p.heuristic(),
+            |p| p.remaining_joltages.iter().all(|j| *j == 0)
+        );
+
+        result.map(|(_, presses)| presses)
+    }
+
+    #[cfg(feature = "highs")]
+    pub fn fewest_presses_linalg_solver(&self) -> Option<usize> {
+        let n = self.joltage_requirements.len();
+
+        let mut vars = variables!();
+        let x: Vec<_> = (0..self.buttons.len())
+            .map(|_| vars.add(variable().min(0).integer()))
+            .collect();
+
+        let mut problem = vars.minimise(x.iter().sum::<Expression>()).using(default_solver);
+
+        for i in 0..n {
+            let mut lhs = Expression::from(0);
+
+            for (button, var) in self.buttons.iter().zip(&x) {
+                if button.contains(&i) {
+                    lhs += var;
+                }
+            }
+
+            // note: might need to use epsilon distance because of f64 limitation; worked on my input
+            problem = problem.with(lhs.eq(self.joltage_requirements[i] as f64));
+        }
+
+        // Infeasible when no presses reach the joltages
+        let solution = problem.solve().ok()?;
+
+        // Round because fuckass linalg solver doesn't support true integers and otherwise it'll trunc
+        Some(x.iter().map(|var| solution.value(*var).round() as usize).sum())
+    }
+
+    /// What the linalg solver does, without the solver: row reduce the buttons against the joltages so the
+    /// pivot buttons follow from the rest, then try every number of presses for the free ones
+    ///
+    /// A button can't be pressed more often than the lowest joltage it adds to, which keeps the search finite,
+    /// and the free buttons rarely number more than two or three.
+    pub fn fewest_presses_integer_search(&self) -> Option<usize> {
+        fn gcd(a: i64, b: i64) -> i64 {
+            if b == 0 { a } else { gcd(b, a % b) }
+        }
+
+        fn search(free: &[(usize, i64)], pivots: &[(usize, Vec<i64>)], presses: &mut [i64], so_far: i64, best: &mut Option<i64>) {
+            if best.is_some_and(|best| so_far >= best) {
+                return;
+            }
+
+            if let Some((&(button, bound), rest)) = free.split_first() {
+                for count in 0..=bound {
+                    presses[button] = count;
+
+                    search(rest, pivots, presses, so_far + count, best);
+                }
+
+                presses[button] = 0;
+
+                return;
+            }
+
+            let mut total = so_far;
+
+            for (button, row) in pivots {
+                let (joltage, coefficients) = row.split_last().unwrap();
+                let left = joltage - coefficients.iter().zip(&*presses).map(|(a, x)| a * x).sum::<i64>();
+                let pivot = coefficients[*button];
+
+                if left % pivot != 0 || left / pivot < 0 {
+                    return;
+                }
+
+                total += left / pivot;
+            }
+
+            if best.is_none_or(|best| total < best) {
+                *best = Some(total);
+            }
+        }
+
+        let buttons = self.buttons.len();
+
+        // One equation per light: the presses of the buttons wired to it add up to its joltage
+        let mut rows: Vec<Vec<i64>> = self.joltage_requirements.iter().enumerate().map(|(light, &joltage)| {
+            self.buttons.iter().map(|button| button.contains(&light) as i64).chain([joltage as i64]).collect()
+        }).collect();
+
+        let mut pivot_buttons = vec![];
+
+        for button in 0..buttons {
+            let Some(row) = (pivot_buttons.len()..rows.len()).find(|&row| rows[row][button] != 0) else {
+                continue;
+            };
+
+            rows.swap(row, pivot_buttons.len());
+
+            let pivot_row = rows[pivot_buttons.len()].clone();
+
+            for (index, row) in rows.iter_mut().enumerate() {
+                let factor = row[button];
+
+                if index == pivot_buttons.len() || factor == 0 {
+                    continue;
+                }
+
+                // Kept in integers, scaled back down so they don't grow out of hand
+                row.iter_mut().zip(&pivot_row).for_each(|(a, p)| *a = *a * pivot_row[button] - p * factor);
+
+                let divisor = row.iter().fold(0, |g, a| gcd(g, a.abs()));
+
+                if divisor > 1 {
+                    row.iter_mut().for_each(|a| *a /= divisor);
+                }
+            }
+
+            pivot_buttons.push(button);
+        }
+
+        // Rows left without a button that still want some joltage can't be reached at all
+        if rows[pivot_buttons.len()..].iter().any(|row| *row.last().unwrap() != 0) {
+            return None;
+        }
+
+        let free: Vec<_> = (0..buttons).filter(|button| !pivot_buttons.contains(button)).map(|button| {
+            (button, self.buttons[button].iter().map(|&light| self.joltage_requirements[light] as i64).min().unwrap_or(0))
+        }).collect();
+        let pivots: Vec<_> = pivot_buttons.into_iter().zip(rows).collect();
+
+        let mut best = None;
+
+        search(&free, &pivots, &mut vec![0; buttons], 0, &mut best);
+
+        best.map(|best| best as usize)
+    }
+}
+
+impl Display for Machine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("[")?;
+
+        for &on in &self.target_indicator_lights {
+            f.write_str(if on { "#" } else { "." })?;
+        }
+
+        f.write_str("]")?;
+
+        for button in &self.buttons {
+            let wires: Vec<_> = button.iter().map(usize::to_string).collect();
+
+            write!(f, " ({})", wires.join(","))?;
+        }
+
+        let joltages: Vec<_> = self.joltage_requirements.iter().map(usize::to_string).collect();
+
+        write!(f, " {{{}}}", joltages.join(","))
+    }
+}
+
+impl Solution for Factory {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Factory::parse(input)
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        self.sum_of_fewest_presses()
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        // factory.machines.iter()
+        //     .enumerate()
+        //     .map(|(index, m)| (index, m.fewest_presses_linalg_solver()))
+        //     .inspect(|(index, count)| println!("{index}: {count}"))
+        //     .map(|(_, count)| count)
+        //     .for_each(|_| {});
+
+        // dbg!(factory.machines[0].fewest_presses_fucking_linalg_solver());
+        // dbg!(factory.machines[124].fewest_presses_fucking_linalg_solver());
+        // dbg!(factory.machines[0].fewest_presses_for_joltage_possibly_more_dumb());
+        // dbg!(factory.machines[1].fewest_presses_for_joltage_possibly_more_dumb());
+        // dbg!(factory.machines[2].fewest_presses_for_joltage_possibly_more_dumb());
+        // // dbg!(factory.machines[0].fewest_presses_for_joltage_less_dumb());
+        // dbg!(factory.sum_of_fewest_joltages_it_wasnt_more_dumb());
+
+        // dbg!(factory.sum_of_fewest_joltages_less_dumb());
+        #[cfg(feature = "highs")]
+        let presses = Machine::fewest_presses_linalg_solver;
+        #[cfg(not(feature = "highs"))]
+        let presses = Machine::fewest_presses_integer_search;
+
+        let progress = Progress::new("machines", self.machines.len());
+
+        self.machines.iter()
+            .enumerate()
+            .map(|(index, m)| {
+                let presses = presses(m).with_context(|| format!("line {}: no presses reach the joltages of {m}", index + 1));
+
+                progress.tick();
+
+                presses
+            })
+            .sum()
+    }
+
+    /// `size` machines, each made solvable by pressing some buttons first and writing down where that got us
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        (0..size).map(|_| {
+            let lights = rng.random_range(3..=10);
+            let mut buttons: Vec<Vec<usize>> = (0..rng.random_range(2..=lights + 3)).map(|_| {
+                let wired = rng.random_range(1..=lights);
+
+                index::sample(rng, lights, wired).into_vec()
+            }).collect();
+
+            // Every light is on at least one button
+            for light in 0..lights {
+                if !buttons.iter().any(|b| b.contains(&light)) {
+                    let button = rng.random_range(0..buttons.len());
+
+                    buttons[button].push(light);
+                }
+            }
+
+            buttons.iter_mut().for_each(|b| b.sort());
+
+            let mut target = vec![false; lights];
+            let mut joltages = vec![0; lights];
+
+            for button in &buttons {
+                let presses = rng.random_range(0..=10);
+
+                for &light in button {
+                    target[light] ^= presses % 2 == 1;
+                    joltages[light] += presses;
+                }
+            }
+
+            let target: String = target.iter().map(|&on| if on { '#' } else { '.' }).collect();
+            let buttons: Vec<_> = buttons.iter().map(|b| {
+                let wires: Vec<_> = b.iter().map(usize::to_string).collect();
+
+                format!("({})", wires.join(","))
+            }).collect();
+            let joltages: Vec<_> = joltages.iter().map(usize::to_string).collect();
+
+            format!("[{target}] {} {{{}}}\n", buttons.join(" "), joltages.join(","))
+        }).collect()
+    }
+
+    const ITEM: &'static str = "machine";
+
+    fn variants(part: Part) -> &'static [Variant<Self>] {
+        // The parts themselves report the machines no presses solve, here they just count as 0
+        fn each(factory: &Factory, presses: fn(&Machine) -> Option<usize>) -> Vec<u64> {
+            let progress = Progress::new("machines", factory.machines.len());
+
+            factory.machines.par_iter().map(|m| presses(m).unwrap_or(0) as u64).inspect(|_| progress.tick()).collect()
+        }
+
+        // The brute force ones take long enough per machine that redoing them after an interruption hurts;
+        // machines that run out of time are left unresolved, and aren't remembered
+        fn each_remembered(factory: &Factory, solver: &str, presses: fn(&Machine, &Deadline) -> Result<Option<usize>, Timeout>) -> Vec<u64> {
+            let memo = Memo::open(solver, 1);
+            let progress = Progress::new("machines", factory.machines.len());
+            let (unresolved, warnings) = (Unresolved::here(), Warnings::here());
+
+            factory.machines.par_iter().enumerate().map(|(index, m)| warnings.within(|| {
+                let key = m.to_string();
+
+                if let Some(count) = memo.get(&key) {
+                    return count as u64;
+                }
+
+                match presses(m, &Deadline::per_item()) {
+                    Ok(Some(count)) => {
+                        memo.insert(&key, count);
+
+                        count as u64
+                    },
+                    Ok(None) => 0,
+                    Err(Timeout) => {
+                        unresolved.note(index);
+
+                        0
+                    },
+                }
+            })).inspect(|_| progress.tick()).collect()
+        }
+
+        match part {
+            Part::One => &[
+                Variant { name: "fewest_presses", items: |f| each(f, Machine::fewest_presses) },
+            ],
+            Part::Two => &[
+                Variant { name: "dumb", items: |f| each_remembered(f, "day10-dumb", Machine::fewest_presses_for_joltage_dumb) },
+                Variant { name: "less_dumb", items: |f| each_remembered(f, "day10-less-dumb", |m, _| Ok(m.fewest_presses_for_joltage_less_dumb())) },
+                Variant { name: "possibly_more_dumb", items: |f| each(f, Machine::fewest_presses_for_joltage_possibly_more_dumb) },
+                #[cfg(feature = "highs")]
+                Variant { name: "linalg", items: |f| each(f, Machine::fewest_presses_linalg_solver) },
+                Variant { name: "integer_search", items: |f| each(f, Machine::fewest_presses_integer_search) },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day10/example.txt");
+
+    #[test]
+    fn example() {
+        let factory = Factory::parse(EXAMPLE).unwrap();
+
+        assert_eq!(factory.sum_of_fewest_presses().unwrap(), 7);
+    }
+
+    #[test]
+    fn nothing_to_press() {
+        let machine = Machine::from_line("[...] (0,1) (2) {2,2,4}").unwrap();
+
+        assert_eq!(machine.fewest_presses(), Some(0));
+        assert_eq!(machine.fewest_presses_for_joltage_dumb(&Deadline::never()), Ok(Some(6)));
+
+        let off = Machine::from_line("[..] (0) (1) {0,0}").unwrap();
+
+        assert_eq!(off.fewest_presses_for_joltage_dumb(&Deadline::never()), Ok(Some(0)));
+        assert_eq!(Machine::from_line("[#.] (1) {0,1}").unwrap().fewest_presses(), None);
+    }
+
+    #[test]
+    fn generated_machines_light_up() {
+        let mut rng = <StdRng as rand::SeedableRng>::seed_from_u64(3);
+        let factory = Factory::parse(&Factory::generate(&mut rng, 50)).unwrap();
+
+        assert!(factory.machines.iter().any(|m| m.target_indicator_lights.iter().all(|on| !on)));
+        assert!(factory.sum_of_fewest_presses().is_ok());
+    }
+
+    #[test]
+    fn unreachable_joltages() {
+        let factory = Factory::parse("[#.] (0) {1,1}").unwrap();
+        let machine = &factory.machines[0];
+
+        assert_eq!(machine.fewest_presses_for_joltage_dumb(&Deadline::never()), Ok(None));
+        assert_eq!(machine.fewest_presses_for_joltage_less_dumb(), None);
+        assert_eq!(machine.fewest_presses_for_joltage_possibly_more_dumb(), None);
+        assert_eq!(machine.fewest_presses_integer_search(), None);
+        assert_eq!(factory.part2().unwrap_err().to_string(), "line 1: no presses reach the joltages of [#.] (0) {1,1}");
+    }
+
+    #[test]
+    fn example_part2() {
+        let factory = Factory::parse(EXAMPLE).unwrap();
+
+        assert_eq!(factory.machines[0].fewest_presses_for_joltage_less_dumb(), Some(10));
+        // assert_eq!(factory.sum_of_fewest_joltages_less_dumb(), 33);
+        // assert_eq!(factory.sum_of_fewest_joltages(), 33);
+        assert_eq!(factory.sum_of_fewest_joltages_more_dumb(), Some(33));
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for line in EXAMPLE.lines() {
+            assert_eq!(Machine::from_line(line).unwrap().to_string(), line);
+        }
+    }
+
+    /// A few lights and buttons, with joltages that some number of presses is known to reach
+    fn small_machines() -> impl Strategy<Value = Machine> {
+        (2..=4usize)
+            .prop_flat_map(|lights| {
+                let button = prop::collection::vec(any::<bool>(), lights);
+
+                (Just(lights), prop::collection::vec((button, 0..=4usize), 1..=4))
+            })
+            .prop_map(|(lights, buttons)| {
+                let mut joltage_requirements = vec![0; lights];
+
+                let buttons = buttons.into_iter().map(|(wired, presses)| {
+                    let mut button: Vec<_> = (0..lights).filter(|&i| wired[i]).collect();
+
+                    if button.is_empty() {
+                        button.push(0);
+                    }
+
+                    button.iter().for_each(|&i| joltage_requirements[i] += presses);
+
+                    button
+                }).collect();
+
+                Machine { target_indicator_lights: vec![false; lights], buttons, joltage_requirements }
+            })
+    }
+
+    #[test]
+    fn integer_search_example() {
+        let factory = Factory::parse(EXAMPLE).unwrap();
+
+        let presses: Vec<_> = factory.machines.iter().map(Machine::fewest_presses_integer_search).collect();
+
+        assert_eq!(presses, [Some(10), Some(12), Some(11)]);
+    }
+
+    proptest! {
+        #[cfg(feature = "highs")]
+        #[test]
+        fn linalg_agrees_with_less_dumb(machine in small_machines()) {
+            prop_assert_eq!(machine.fewest_presses_linalg_solver(), machine.fewest_presses_for_joltage_less_dumb());
+        }
+
+        #[test]
+        fn integer_search_agrees_with_less_dumb(machine in small_machines()) {
+            prop_assert_eq!(machine.fewest_presses_integer_search(), machine.fewest_presses_for_joltage_less_dumb());
+        }
+    }
+}
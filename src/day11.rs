@@ -0,0 +1,328 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::bail;
+use rand::{Rng as _, rngs::StdRng, seq::{SliceRandom as _, index}};
+
+use crate::{parse::{self, Cursor, ParseError}, solution::Solution};
+
+#[derive(Debug)]
+pub struct Hiroshima {
+    connections: HashMap<String, Vec<String>>
+}
+
+impl Hiroshima {
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let mut connections = HashMap::new();
+        let mut devices = vec![];
+
+        fn device(line: &mut Cursor) -> Result<String, ParseError> {
+            let name = line.take_while(|c| c.is_ascii_alphanumeric());
+
+            if name.is_empty() {
+                return Err(line.error("a device name"));
+            }
+
+            Ok(name.to_owned())
+        }
+
+        for mut line in parse::lines(s) {
+            let a = device(&mut line)?;
+            line.expect(":")?;
+
+            let mut c = vec![];
+
+            while !line.is_empty() {
+                line.expect(" ")?;
+                c.push(device(&mut line)?);
+            }
+
+            devices.push(a.clone());
+            connections.insert(a, c);
+        }
+
+        let hiroshima = Self {
+            connections
+        };
+
+        // The path counts recurse along the wires, a loop would have them going round it forever
+        if let Some(device) = hiroshima.find_loop(&devices) {
+            bail!("{device} leads back round to itself, so there's no end to the paths through it");
+        }
+
+        Ok(hiroshima)
+    }
+
+    /// A device on a loop, if there is one, looking from each of `starts` in turn
+    fn find_loop<'a>(&'a self, starts: &'a [String]) -> Option<&'a str> {
+        // false while a device is on the current path, true once everything after it has been looked at
+        let mut done = HashMap::new();
+
+        for start in starts {
+            if done.contains_key(start.as_str()) {
+                continue;
+            }
+
+            done.insert(start.as_str(), false);
+
+            // Kept on the heap, a long chain of devices would overflow the call stack
+            let mut path = vec![(start.as_str(), 0)];
+
+            while let Some(&mut (current, ref mut wire)) = path.last_mut() {
+                let Some(next) = self.connections.get(current).and_then(|nexts| nexts.get(*wire)) else {
+                    done.insert(current, true);
+                    path.pop();
+
+                    continue;
+                };
+
+                *wire += 1;
+
+                match done.get(next.as_str()) {
+                    Some(false) => return Some(next),
+                    Some(true) => {},
+                    None => {
+                        done.insert(next.as_str(), false);
+                        path.push((next.as_str(), 0));
+                    },
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn count_all_paths(&self, from: &str, to: &str) -> usize {
+        self.count_paths_where(from, to, |_| true)
+    }
+
+    /// Walks every single path from `from` to `to`, counting the ones `keep` likes
+    pub fn count_paths_where(&self, from: &str, to: &str, keep: impl Fn(&[&str]) -> bool) -> usize {
+        let mut stack = vec![from];
+
+        fn search<'a>(
+            hiroshima: &'a Hiroshima,
+            stack: &mut Vec<&'a str>,
+            keep: &dyn Fn(&[&str]) -> bool,
+            end: &str,
+            current: &'a str
+        ) -> usize {
+            if current == end {
+                return keep(stack) as usize;
+            }
+
+            let Some(nexts) = hiroshima.connections.get(current) else {
+                log::warn!("{current} doesn't lead anywhere, so it never gets to {end}");
+
+                return 0;
+            };
+            let mut sum = 0;
+
+            for next in nexts {
+                stack.push(next.as_str());
+
+                sum += search(hiroshima, stack, keep, end, next);
+
+                stack.pop();
+            }
+
+            sum
+        }
+
+
+        search(self, &mut stack, &keep, to, from)
+    }
+
+    /// Paths from `from` to `to` through both dac and fft, or an error if there are more of them than a usize holds
+    pub fn count_all_paths_nanalog(&self, from: &str, to: &str) -> anyhow::Result<usize> {
+        // DP hard, DP often. But especially hard
+        // The memo is per call, a global one would mix up the paths of different reactors
+        type Memo<'a> = HashMap<(&'a str, bool, bool), usize>;
+
+        fn search_cached<'a>(
+            hiroshima: &'a Hiroshima,
+            memo: &mut Memo<'a>,
+            end: &str,
+            current: &'a str,
+            seen_dac: bool,
+            seen_fft: bool
+        ) -> Option<usize> {
+            if current == end {
+                if seen_dac && seen_fft {
+                    return Some(1);
+                } else {
+                    return Some(0);
+                }
+            }
+
+            if let Some(&sum) = memo.get(&(current, seen_dac, seen_fft)) {
+                return Some(sum);
+            }
+
+            let key = (current, seen_dac, seen_fft);
+            let seen_dac = seen_dac || current == "dac";
+            let seen_fft = seen_fft || current == "fft";
+
+            let nexts = match hiroshima.connections.get(current) {
+                Some(n) => n,
+                None => return Some(0),
+            };
+
+            let mut sum: usize = 0;
+            for next in nexts {
+                sum = sum.checked_add(search_cached(hiroshima, memo, end, next, seen_dac, seen_fft)?)?;
+            }
+
+            memo.insert(key, sum);
+
+            Some(sum)
+        }
+
+        match search_cached(self, &mut Memo::new(), to, from, false, false) {
+            Some(paths) => Ok(paths),
+            None => bail!("there are more paths from {from} to {to} than fit in a {}-bit number", usize::BITS),
+        }
+    }
+}
+
+impl Solution for Hiroshima {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    /// Part 2's path count grows quickly with the size, from about 1300 devices it can stop fitting a u64
+    const MAX_SIZE: usize = 1_000;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Hiroshima::parse(input)
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        Ok(self.count_all_paths("you", "out"))
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        // self.count_all_paths("svr", "out") real cute making part 1 a path without quadrillions of routes
+        self.count_all_paths_nanalog("svr", "out")
+    }
+
+    /// About `size` devices wired forwards only, so there are no loops; svr comes first, dac and fft somewhere
+    /// in the middle and you close to the end, where part 1's walk of every path stays affordable
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let count = size.max(4) - 4;
+        let mut names = vec![];
+        let mut taken = HashSet::new();
+
+        // MAX_SIZE keeps this well short of the 26³ names there are, so picking a new one stays quick
+        while names.len() < count {
+            let name: String = (0..3).map(|_| char::from(rng.random_range(b'a'..=b'z'))).collect();
+
+            if !["svr", "you", "dac", "fft", "out"].contains(&name.as_str()) && taken.insert(name.clone()) {
+                names.push(name);
+            }
+        }
+
+        let mut middle = ["dac", "fft"];
+        middle.shuffle(rng);
+
+        let third = names.len() / 3;
+
+        names.insert(0, "svr".into());
+        names.insert(third + 1, middle[0].into());
+        names.insert(2 * third + 2, middle[1].into());
+        names.insert(names.len().saturating_sub(12).max(2 * third + 3), "you".into());
+        names.push("out".into());
+
+        (0..names.len() - 1).map(|i| {
+            let later = names.len() - i - 1;
+            let wires = rng.random_range(1..=later.min(2));
+            let mut picked = index::sample(rng, later, wires).into_vec();
+
+            // Always on to the very next one, so there's a way through svr, dac, fft and you all the way out
+            if !picked.contains(&0) {
+                picked[0] = 0;
+            }
+
+            let outputs: Vec<_> = picked.iter().map(|j| names[i + 1 + j].as_str()).collect();
+
+            format!("{}: {}\n", names[i], outputs.join(" "))
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(unused)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day11/example.txt");
+
+    const EXAMPLE2: &str = include_str!("../examples/day11/example2.txt");
+
+    #[test]
+    fn example() {
+        let reactor = Hiroshima::parse(EXAMPLE).unwrap();
+
+        assert_eq!(reactor.count_all_paths("you", "out"), 5);
+    }
+
+    #[test]
+    fn example_part2() {
+        let reactor = Hiroshima::parse(EXAMPLE2).unwrap();
+
+        assert_eq!(reactor.count_all_paths("svr", "out"), 8);
+        assert_eq!(reactor.count_all_paths_nanalog("svr", "out").unwrap(), 2);
+    }
+
+    #[test]
+    fn too_many_paths() {
+        // Every device on to the next two, so the path count goes up like Fibonacci and is past a u64 by 100
+        let chain: Vec<_> = ["svr", "dac", "fft"].into_iter().map(String::from).chain((0..100).map(|i| format!("d{i}"))).chain(["out".into()]).collect();
+        let input: String = (0..chain.len() - 1).map(|i| format!("{}: {}\n", chain[i], chain[i + 1..].iter().take(2).cloned().collect::<Vec<_>>().join(" "))).collect();
+        let reactor = Hiroshima::parse(&input).unwrap();
+
+        assert!(reactor.count_all_paths_nanalog("svr", "out").is_err());
+    }
+
+    #[test]
+    fn loops() {
+        let err = Hiroshima::parse("svr: aaa\naaa: bbb out\nbbb: ccc\nccc: aaa\n").unwrap_err();
+        assert!(err.to_string().starts_with("aaa leads back round"), "{err}");
+
+        assert!(Hiroshima::parse("you: you\n").is_err());
+
+        // Meeting up again further on isn't a loop
+        assert!(Hiroshima::parse("svr: aaa bbb\naaa: ccc\nbbb: ccc\nccc: out\n").is_ok());
+    }
+
+    /// svr, out and a shuffled middle with dac and fft in it, wired forwards so there are no loops
+    fn reactors() -> impl Strategy<Value = Hiroshima> {
+        const MIDDLE: [&str; 8] = ["dac", "fft", "aaa", "bbb", "ccc", "ddd", "eee", "ggg"];
+
+        (2..=MIDDLE.len())
+            .prop_flat_map(|n| Just(MIDDLE[..n].to_vec()).prop_shuffle())
+            .prop_flat_map(|middle| {
+                let devices: Vec<_> = ["svr"].into_iter().chain(middle).chain(["out"]).collect();
+                let wires = prop::collection::vec(prop::bool::weighted(0.5), devices.len() * devices.len());
+
+                (Just(devices), wires)
+            })
+            .prop_map(|(devices, wires)| {
+                let n = devices.len();
+                let connections = (0..n - 1).map(|i| {
+                    (devices[i].to_owned(), (i + 1..n).filter(|j| wires[i * n + j]).map(|j| devices[j].to_owned()).collect())
+                }).collect();
+
+                Hiroshima { connections }
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn nanalog_agrees_with_walking_every_path(reactor in reactors()) {
+            let through_both = reactor.count_paths_where("svr", "out", |path| path.contains(&"dac") && path.contains(&"fft"));
+
+            prop_assert_eq!(reactor.count_all_paths_nanalog("svr", "out").unwrap(), through_both);
+        }
+    }
+}
@@ -0,0 +1,672 @@
+use std::{collections::HashSet, fmt::Display};
+#[cfg(feature = "highs")]
+use good_lp::{
+    Expression, Solution as _, SolverModel, Variable, constraint, default_solver, solvers::highs::HighsSolution, variable, variables
+};
+use rand::{Rng as _, rngs::StdRng};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::{deadline::{Deadline, Timeout, Unresolved}, grid::Grid, logger::Warnings, memo::Memo, parse::{self, Cursor, ParseError}, progress::Progress, solution::{Freebie, Solution}};
+#[cfg(feature = "highs")]
+use crate::workers;
+
+#[cfg(feature = "highs")]
+/// Roughly what HiGHS ends up taking per placement, the 50x50 regions have ~100k of them and took 3-4gb
+const BYTES_PER_PLACEMENT: u64 = 32 << 10;
+
+#[derive(Debug)]
+pub struct BullshitPacking {
+    shapes: Vec<Shape>,
+    #[allow(unused)]
+    shape_variants: Vec<ShapeVariant>,
+    regions: Vec<Region>
+}
+
+#[derive(Debug)]
+pub struct Region {
+    width: usize,
+    height: usize,
+    required_presents: Vec<usize>
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Shape {
+    cells: Grid<bool>
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ShapeVariant {
+    derived_index: usize,
+    cells: Grid<bool>
+}
+
+#[cfg(feature = "highs")]
+struct Placement {
+    shape_id: usize,
+    variant_id: usize,
+    start_x: usize,
+    start_y: usize,
+    covered_cells: Vec<usize>,
+    var: Option<Variable>
+}
+
+impl Display for Shape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cells.map(|&filled| if filled { '#' } else { '.' }))
+    }
+}
+
+impl Display for Region {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x{}:", self.width, self.height)?;
+
+        for count in &self.required_presents {
+            write!(f, " {count}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for ShapeVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cells.map(|&filled| if filled { '#' } else { '.' }))
+    }
+}
+
+impl Shape {
+    fn from_lines<'a>(lines: &mut impl Iterator<Item = Cursor<'a>>) -> Result<Self, ParseError> {
+        let cells = Grid::from_lines(lines.take_while(|line| !line.is_empty()), &[('#', true), ('.', false)])?;
+
+        Ok(Self { cells })
+    }
+
+    fn variants(&self, shape_index: usize) -> Vec<ShapeVariant> {
+        let mut set = HashSet::new();
+        let mut list = vec![];
+        let mut shape = self.cells.clone();
+
+        for _ in 0..4 {
+            let next = shape.rotated_90deg();
+            // Flipping horizontally (or vertically) is all we need on top of the rotations
+            let flipped = shape.flipped();
+
+            let a = ShapeVariant { derived_index: shape_index, cells: shape };
+            let b = ShapeVariant { derived_index: shape_index, cells: flipped };
+
+            // eprintln!("rotated:\n{a}");
+            // eprintln!("flipped:\n{b}");
+
+            // Filter duplicates while retaining order
+            if set.insert(a.clone()) {
+                list.push(a);
+            }
+
+            if set.insert(b.clone()) {
+                list.push(b);
+            }
+
+            shape = next;
+        }
+
+        list
+    }
+
+    fn cell_count(&self) -> usize {
+        self.cells.cells().filter(|(_, filled)| **filled).count()
+    }
+}
+
+impl BullshitPacking {
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let mut shapes = vec![];
+        let mut regions = vec![];
+        let mut lines = parse::lines(s).peekable();
+
+        while let Some(line) = lines.peek() && line.rest().trim_start_matches(|c: char| c.is_ascii_digit() || c == ':').is_empty() {
+            let mut line = lines.next().unwrap();
+            let at = line.clone();
+
+            if line.number::<usize>()? != shapes.len() {
+                return Err(at.error(format_args!("shape {} to come next", shapes.len())).into());
+            }
+
+            line.expect(":")?;
+
+            let first = lines.peek().cloned().unwrap_or(line);
+            let shape = Shape::from_lines(&mut lines)?;
+
+            // Nothing to place, and nowhere to anchor it
+            if shape.cell_count() == 0 {
+                return Err(first.error("a shape with at least one #").into());
+            }
+
+            shapes.push(shape);
+        }
+
+        // The regions and required presents
+        for mut line in lines {
+            let width = line.number()?;
+            line.expect("x")?;
+            let height = line.number()?;
+            line.expect(":")?;
+
+            let mut required_presents = vec![];
+
+            while required_presents.len() < shapes.len() {
+                line.expect(" ")?;
+                required_presents.push(line.number()?);
+            }
+
+            line.end()?;
+
+            regions.push(Region {
+                width,
+                height,
+                required_presents
+            });
+        }
+
+        let shape_variants = shapes.iter().enumerate().flat_map(|(index, shape)| shape.variants(index)).collect();
+
+        Ok(Self {
+            shapes,
+            shape_variants,
+            regions
+        })
+    }
+}
+
+impl Region {
+    // I'm gonna guess that brute forcing won't work
+    // First idea: Extend the weird shapes into larger, rectangle-shaped objects that might fit
+    // Then pass it to a rectangle solver like rectpack or crunch
+    // Not definitely correct and doesn't solve the problem of exact cover without missing some cases
+    // Can be tried repeatedly with different rectangle combinations but I'm still not sure it'll fit everything
+    // And verifying stragglers might be hard
+    //
+    // Second idea: I just used a linear programming solver with integer variables for day 10
+    // No more dependencies, and each cell could be represented as a 0-1 integer variable
+    // with a constraint on being 0 or 1
+
+    pub fn might_fit_trivially(&self, shapes: &[Shape]) -> bool {
+        let needed_shapes: Vec<_> = self.required_presents.iter().enumerate().flat_map(|(index, count)| {
+            (0..*count).map(move |_| shapes[index].clone())
+        }).collect();
+
+        if needed_shapes.iter().fold(0, |sum, shape| sum + shape.cell_count()) > self.width * self.height {
+            return false;
+        }
+
+        true
+    }
+
+    #[cfg(feature = "highs")]
+    #[allow(unused)]
+    /// Hands the region to HiGHS, which can take hours; it gets however long `deadline` leaves
+    pub fn can_fit_shapes(&self, shapes: &[Shape], deadline: &Deadline) -> Result<bool, Timeout> {
+        let mut needed_shapes: Vec<_> = self.required_presents.iter().enumerate().flat_map(|(index, count)| {
+            (0..*count).map(move |_| shapes[index].clone())
+        }).collect();
+
+        // It was worth a shot
+        if needed_shapes.iter().fold(0, |sum, shape| sum + shape.cell_count()) > self.width * self.height {
+            // Wtf it actually saved time
+            log::warn!("early return; you shouldn't see this if you filter by might_fit_trivially");
+
+            return Ok(false);
+        }
+
+        fn generate_placements(
+            shape_id: usize,
+            variants: &[ShapeVariant],
+            region_w: usize,
+            region_h: usize,
+        ) -> Vec<Placement> {
+            let mut placements = Vec::new();
+
+            for (variant_id, v) in variants.iter().enumerate() {
+                // Turned this way it's wider or taller than the region
+                let (Some(last_y), Some(last_x)) = (region_h.checked_sub(v.cells.height()), region_w.checked_sub(v.cells.width())) else {
+                    continue;
+                };
+
+                for y in 0..=last_y {
+                    for x in 0..=last_x {
+                        let mut cells = Vec::new();
+                        let mut valid = true;
+
+                        for ((dx, dy), &filled) in v.cells.cells() {
+                            if filled {
+                                let cx = x + dx;
+                                let cy = y + dy;
+                                cells.push(cy * region_w + cx);
+                            }
+                        }
+
+                        if valid {
+                            placements.push(Placement {
+                                shape_id,
+                                variant_id,
+                                start_x: x,
+                                start_y: y,
+                                covered_cells: cells,
+                                var: None,
+                            });
+                        }
+                    }
+                }
+            }
+
+            placements
+        }
+
+        let mut vars = variables!();
+        let mut placements = vec![];
+
+        for (index, shape) in shapes.iter().enumerate() {
+            let variants = shape.variants(index);
+
+            placements.extend(generate_placements(index, &variants, self.width, self.height));
+        }
+
+        deadline.check()?;
+
+        // Each solver can take up to 3-4gb, and I don't have much more than 20gb to spare for this
+        // I should've bought 64gb of ram
+        let _memory = workers::reserve(placements.len() as u64 * BYTES_PER_PLACEMENT);
+
+        // Waiting for room could've taken a while
+        deadline.check()?;
+
+        for p in placements.iter_mut() {
+            p.var = Some(vars.add(variable().integer().min(0).max(1)));
+        }
+
+        // Flatten cells into a linear array
+        let mut cell_to_vars = vec![Vec::<Variable>::new(); self.width * self.height];
+
+        for p in &placements {
+            for &cell in &p.covered_cells {
+                cell_to_vars[cell].push(p.var.unwrap());
+            }
+        }
+
+        let mut model = vars.minimise(0).using(default_solver);
+
+        if let Some(remaining) = deadline.remaining() {
+            model = model.set_time_limit(remaining.as_secs_f64());
+        }
+
+        for vars in cell_to_vars {
+            if !vars.is_empty() {
+                model = model.with(constraint!(
+                    vars.iter().sum::<Expression>() <= 1
+                ));
+            }
+        }
+
+        let mut shape_to_vars = vec![Vec::<Variable>::new(); shapes.len()];
+
+        for p in &placements {
+            shape_to_vars[p.shape_id].push(p.var.unwrap());
+        }
+
+        for (index, vars) in shape_to_vars.iter().enumerate() {
+            let required = self.required_presents[index] as f64;
+
+            model = model.with(vars.iter().sum::<Expression>().eq(self.required_presents[index] as f64));
+        }
+
+        let solution = model.solve();
+
+        // Whatever HiGHS had when the time limit hit isn't an answer
+        deadline.check()?;
+
+        if let Ok(solution) = &solution {
+            for p in &placements {
+                #[allow(clippy::collapsible_if)]
+                if solution.value(p.var.unwrap()) > 0.5 {
+                    if solution.value(p.var.unwrap()) > 1.5 {
+                        log::warn!("Greater than 1.0 {}", solution.value(p.var.unwrap()));
+                    }
+
+                    // println!(
+                    //     "Shape {} variant {} at ({}, {})",
+                    //     p.shape_id, p.variant_id, p.start_x, p.start_y
+                    // );
+                }
+            }
+
+            let verified = self.verify_solution(shapes, &placements, solution);
+            if !verified {
+                log::warn!("Did not pass verification");
+                return Ok(false);
+            }
+        }
+
+        Ok(solution.is_ok())
+    }
+
+    #[cfg(feature = "highs")]
+    fn verify_solution(&self, shapes: &[Shape], placements: &[Placement], solution: &HighsSolution) -> bool {
+        let needed_cells_for_shapes = self.cells_needed(shapes);
+
+        let mut counts = Grid::new(self.width, self.height, 0usize);
+
+        let mut shape_variants = vec![vec![]; shapes.len()];
+
+        for (shape_index, shape) in shapes.iter().enumerate() {
+            shape_variants[shape_index] = shape.variants(shape_index);
+        }
+
+        for placement in placements {
+            if solution.value(placement.var.unwrap()) < 0.5 {
+                continue;
+            }
+
+            let variant = &shape_variants[placement.shape_id][placement.variant_id];
+
+            for ((x, y), &filled) in variant.cells.cells() {
+                if filled {
+                    counts[(placement.start_x + x, placement.start_y + y)] += 1;
+                }
+            }
+        }
+
+        let verified = counts.cells().all(|(_, &count)| count <= 1);
+        let cell_count: usize = counts.cells().map(|(_, &count)| count).sum();
+
+        log::debug!("{counts}\ncells filled: {cell_count} cells needed: {needed_cells_for_shapes} rect size: {}", self.rect_size());
+
+        verified
+    }
+
+    /// Without HiGHS built in, backtracking is all there is
+    #[cfg(not(feature = "highs"))]
+    pub fn can_fit_shapes(&self, shapes: &[Shape], deadline: &Deadline) -> Result<bool, Timeout> {
+        self.can_fit_shapes_backtracking(shapes, deadline)
+    }
+
+    /// Packs the presents by hand: the first empty cell (in reading order) either becomes the first cell of a
+    /// present that's still needed, or is left empty as long as there's room to spare for that
+    pub fn can_fit_shapes_backtracking(&self, shapes: &[Shape], deadline: &Deadline) -> Result<bool, Timeout> {
+        struct Search<'a> {
+            width: usize,
+            /// Every variant of every shape, as offsets from its first cell
+            variants: &'a [Vec<Vec<(isize, usize)>>],
+            filled: Vec<bool>,
+            remaining: Vec<usize>,
+            spare: usize,
+            steps: u64,
+            deadline: &'a Deadline,
+        }
+
+        impl Search<'_> {
+            fn cell(&self, at: usize, (dx, dy): (isize, usize)) -> Option<usize> {
+                let x = (at % self.width).checked_add_signed(dx).filter(|&x| x < self.width)?;
+                let y = at / self.width + dy;
+
+                Some(y * self.width + x).filter(|&cell| cell < self.filled.len())
+            }
+
+            fn set(&mut self, at: usize, offsets: &[(isize, usize)], filled: bool) {
+                for &offset in offsets {
+                    let cell = self.cell(at, offset).unwrap();
+
+                    self.filled[cell] = filled;
+                }
+            }
+
+            fn fill_from(&mut self, from: usize) -> Result<bool, Timeout> {
+                self.steps += 1;
+
+                if self.steps.is_multiple_of(4096) {
+                    self.deadline.check()?;
+                }
+
+                if self.remaining.iter().all(|&count| count == 0) {
+                    return Ok(true);
+                }
+
+                let Some(at) = (from..self.filled.len()).find(|&cell| !self.filled[cell]) else {
+                    return Ok(false);
+                };
+
+                let variants = self.variants;
+
+                for (shape, offsets) in variants.iter().enumerate().flat_map(|(shape, variants)| variants.iter().map(move |v| (shape, v))) {
+                    if self.remaining[shape] == 0 || !offsets.iter().all(|&offset| self.cell(at, offset).is_some_and(|cell| !self.filled[cell])) {
+                        continue;
+                    }
+
+                    self.set(at, offsets, true);
+                    self.remaining[shape] -= 1;
+
+                    let fits = self.fill_from(at + 1)?;
+
+                    self.set(at, offsets, false);
+                    self.remaining[shape] += 1;
+
+                    if fits {
+                        return Ok(true);
+                    }
+                }
+
+                if self.spare == 0 {
+                    return Ok(false);
+                }
+
+                self.spare -= 1;
+                self.filled[at] = true;
+
+                let fits = self.fill_from(at + 1)?;
+
+                self.spare += 1;
+                self.filled[at] = false;
+
+                Ok(fits)
+            }
+        }
+
+        if !self.might_fit_trivially(shapes) {
+            return Ok(false);
+        }
+
+        let variants: Vec<Vec<_>> = shapes.iter().enumerate().map(|(index, shape)| {
+            shape.variants(index).iter().map(|variant| {
+                let cells: Vec<_> = variant.cells.cells().filter(|(_, filled)| **filled).map(|(at, _)| at).collect();
+                let (first_x, first_y) = cells[0];
+
+                cells.iter().map(|&(x, y)| (x as isize - first_x as isize, y - first_y)).collect()
+            }).collect()
+        }).collect();
+
+        let mut search = Search {
+            width: self.width,
+            variants: &variants,
+            filled: vec![false; self.rect_size()],
+            remaining: self.required_presents.clone(),
+            spare: self.rect_size() - self.cells_needed(shapes),
+            steps: 0,
+            deadline,
+        };
+
+        search.fill_from(0)
+    }
+
+    pub fn cells_needed(&self, shapes: &[Shape]) -> usize {
+        let needed_shapes: Vec<_> = self.required_presents.iter().enumerate().flat_map(|(index, count)| {
+            (0..*count).map(move |_| shapes[index].clone())
+        }).collect();
+
+        needed_shapes.iter().fold(0, |sum, shape| sum + shape.cell_count())
+    }
+
+    /// Everything whether it fits depends on, which is the presents as much as the region
+    fn memo_key(&self, shapes: &[Shape]) -> String {
+        let shapes: String = shapes.iter().map(|shape| format!("{shape}\n")).collect();
+
+        format!("{shapes}{self}")
+    }
+
+    pub fn rect_size(&self) -> usize {
+        self.width * self.height
+    }
+}
+
+impl Solution for BullshitPacking {
+    type Part1 = usize;
+    type Part2 = Freebie;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        BullshitPacking::parse(input)
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        // dbg!(packing.shapes.len());
+        // dbg!(packing.shape_variants.len());
+
+        // for variant in packing.shape_variants {
+        //     // eprintln!("{}:\n{}", variant.derived_index, variant);
+        // }
+
+        // I've yet to see a non-trivial result churn out false
+        let non_trivial_results = Memo::<bool>::open("day12-regions", 1);
+
+        log::info!("regions: {}", self.regions.len());
+        log::info!("non-trivial regions that might fit (upper ceiling): {}", self.regions.iter().filter(|region| region.might_fit_trivially(&self.shapes)).count());
+        log::debug!("regions that trivially don't fit: {}", self.regions.iter().enumerate().filter(|(_, region)| !region.might_fit_trivially(&self.shapes)).map(|(i, _)| i.to_string()).collect::<Vec<_>>().join(","));
+
+        // Sort by tightest fits, keeping the input order around for the logs
+        let mut regions: Vec<_> = self.regions.iter().enumerate().collect();
+        regions.sort_by_key(|(_, region)| region.rect_size() as i64 - region.cells_needed(&self.shapes) as i64);
+
+        if let (Some((_, tightest)), Some((_, roomiest))) = (regions.first(), regions.last()) {
+            log::debug!("{}", tightest.rect_size() as i64 - tightest.cells_needed(&self.shapes) as i64);
+            log::debug!("{}", roomiest.rect_size() as i64 - roomiest.cells_needed(&self.shapes) as i64);
+        }
+
+        regions.retain(|(_, region)| region.might_fit_trivially(&self.shapes));
+
+        let progress = Progress::new("regions", regions.len());
+        let (unresolved, warnings) = (Unresolved::here(), Warnings::here());
+
+        let fitting = regions.par_iter()
+            .filter(|&&(index, region)| warnings.within(|| {
+                let key = region.memo_key(&self.shapes);
+
+                if let Some(fits) = non_trivial_results.get(&key) {
+                    log::debug!("{index} fits: {fits} (cached)");
+                    progress.tick();
+
+                    return fits;
+                }
+
+                let fits = region.can_fit_shapes(&self.shapes, &Deadline::per_item());
+
+                progress.tick();
+
+                let Ok(fits) = fits else {
+                    log::debug!("{index} ran out of time");
+                    unresolved.note(index);
+
+                    return false;
+                };
+
+                log::debug!("{index} fits: {fits}");
+
+                non_trivial_results.insert(&key, fits);
+
+                fits
+            }))
+            .count();
+
+        Ok(fitting)
+
+        // Fuck you Eric
+    }
+
+    fn part2(&self) -> anyhow::Result<Freebie> {
+        Ok(Freebie)
+    }
+
+    /// Six 3x3 presents and `size` regions, some roomy and some hopeless
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut s = String::new();
+
+        for index in 0..6 {
+            let shape = loop {
+                let cells = Grid::from_fn(3, 3, |_, _| rng.random_bool(0.7));
+
+                if cells.cells().filter(|(_, filled)| **filled).count() >= 5 {
+                    break cells;
+                }
+            };
+
+            s += &format!("{index}:\n{}\n", shape.map(|&filled| if filled { '#' } else { '.' }));
+        }
+
+        for _ in 0..size {
+            let (width, height) = (rng.random_range(4..=50), rng.random_range(4..=50));
+            // Up to about as many presents as there's room for if they were squares
+            let presents: Vec<_> = (0..6).map(|_| rng.random_range(0..=width * height / 9 / 5).to_string()).collect();
+
+            s += &format!("{width}x{height}: {}\n", presents.join(" "));
+        }
+
+        s
+    }
+}
+
+#[cfg(test)]
+#[allow(unused)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day12/example.txt");
+
+    #[test]
+    fn example() {
+        let packing = BullshitPacking::parse(EXAMPLE).unwrap();
+
+        let fits: Vec<_> = packing.regions.iter()
+            .map(|region| region.can_fit_shapes(&packing.shapes, &Deadline::never()).unwrap())
+            .collect();
+
+        assert_eq!(fits, [true, true, false]);
+    }
+
+    #[test]
+    fn backtracking_example() {
+        let packing = BullshitPacking::parse(EXAMPLE).unwrap();
+
+        let fits: Vec<_> = packing.regions.iter()
+            .map(|region| region.can_fit_shapes_backtracking(&packing.shapes, &Deadline::never()).unwrap())
+            .collect();
+
+        assert_eq!(fits, [true, true, false]);
+    }
+
+    #[test]
+    fn awkward_shapes_and_regions() {
+        let err = BullshitPacking::parse("0:\n###\n\n1:\n...\n...\n\n4x4: 1 1").err().unwrap();
+
+        assert_eq!(err.to_string(), "line 5, column 1: expected a shape with at least one #, found \".\"");
+
+        // A bar of four fits standing up or lying down, but not in a 2x2
+        let packing = BullshitPacking::parse("0:\n####\n\n1x4: 1\n4x1: 1\n2x2: 1").unwrap();
+
+        for solve in [Region::can_fit_shapes, Region::can_fit_shapes_backtracking] {
+            let fits: Vec<_> = packing.regions.iter().map(|region| solve(region, &packing.shapes, &Deadline::never()).unwrap()).collect();
+
+            assert_eq!(fits, [true, true, false]);
+        }
+    }
+
+    #[test]
+    fn example_part2() {
+        let packing = BullshitPacking::parse(EXAMPLE).unwrap();
+        assert_eq!(packing.part2().unwrap().to_string(), "free");
+    }
+}
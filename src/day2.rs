@@ -0,0 +1,375 @@
+use std::{collections::HashSet, fmt::Write, ops::RangeInclusive};
+
+use rand::{Rng as _, rngs::StdRng, seq::SliceRandom as _};
+
+use crate::{parse, runner::Part, solution::{Solution, Variant}};
+
+/// The biggest ID with 19 digits, the next power of ten is past what a u64 holds
+const MAX_ID: u64 = 10u64.pow(19) - 1;
+
+#[derive(Clone)]
+pub struct Elfilter {
+    ranges: Vec<RangeInclusive<u64>>
+}
+
+/// Splits the digits of `n` into numbers of `piece_digits` digits each, most significant first
+///
+/// ```
+/// use aoc2025::day2::split_pieces;
+///
+/// assert_eq!(split_pieces(123456, 2).collect::<Vec<_>>(), vec![12, 34, 56]);
+/// assert_eq!(split_pieces(1212, 2).collect::<Vec<_>>(), vec![12, 12]);
+/// ```
+pub fn split_pieces(n: u64, piece_digits: u32) -> impl Iterator<Item = u64> {
+    assert!(n > 0 && piece_digits > 0);
+
+    let number_digits = Elfilter::count_digits(n);
+    assert!(number_digits.is_multiple_of(piece_digits));
+
+    let piece_divisor = 10u64.pow(piece_digits);
+
+    std::iter::successors(Some((number_digits - piece_digits) as i32), move |&d| {
+        let next = d - piece_digits as i32;
+        (next >= 0).then_some(next)
+    })
+    .map(move |digits_to_truncate| {
+        (n / 10u64.pow(digits_to_truncate as u32)) % piece_divisor
+    })
+}
+
+impl Elfilter {
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let mut ranges = vec![];
+
+        // Ranges are comma separated, but may wrap onto the next line after a comma
+        for mut line in parse::lines(s) {
+            loop {
+                line.skip_whitespace();
+
+                if line.is_empty() {
+                    break;
+                }
+
+                let start = line.clone();
+                let first: u64 = line.number()?;
+                line.expect("-")?;
+                let end = line.clone();
+                let last: u64 = line.number()?;
+
+                if first == 0 || first > last {
+                    return Err(start.error("a range of positive IDs going upwards").into());
+                }
+
+                if last > MAX_ID {
+                    return Err(end.error(format_args!("an ID of at most {MAX_ID}")).into());
+                }
+
+                ranges.push(first..=last);
+
+                line.skip_whitespace();
+
+                if !line.is_empty() {
+                    line.expect(",")?;
+                }
+            }
+        }
+
+        Ok(Self { ranges })
+    }
+
+    /// Every range on its own
+    pub fn each_range(&self) -> impl Iterator<Item = Elfilter> {
+        self.ranges.iter().map(|range| Elfilter { ranges: vec![range.clone()] })
+    }
+
+    pub fn normalize_ranges_log10(&mut self) {
+        self.ranges = std::mem::take(&mut self.ranges).into_iter().flat_map(|r| {
+            let s = r.start().ilog10();
+            let e = r.end().ilog10();
+
+            (s..=e).map(move |i| {
+                let start = 10u64.pow(i);
+                let end = 10u64.pow(i + 1) - 1;
+
+                start.max(*r.start())..=end.min(*r.end())
+            })
+        }).collect();
+    }
+
+    fn count_digits(num: u64) -> u32 {
+        num.ilog10() + 1
+    }
+
+    pub fn simple_sieve(&self) -> u64 {
+        let mut buf = String::new();
+        let mut invalidsum = 0;
+
+        for range in self.ranges.iter() {
+            for i in range.clone() {
+                buf.clear();
+
+                write!(&mut buf, "{i}").unwrap();
+
+                if buf.len().is_multiple_of(2) {
+                    let mid = buf.len() / 2;
+
+                    if buf[..mid] == buf[mid..] {
+                        invalidsum += i;
+                    }
+                }
+            }
+        }
+
+        invalidsum
+    }
+
+    pub fn repeat_sieve(&self) -> u64 {
+        let mut buf = String::new();
+        let mut invalidsum = 0;
+
+        for range in self.ranges.iter() {
+            'numbers_loop:
+            for n in range.clone() {
+                buf.clear();
+
+                write!(&mut buf, "{n}").unwrap();
+
+                let slice = buf.as_bytes();
+
+                'length_loop:
+                for i in 0..(slice.len() / 2) {
+                    let i = i + 1;
+
+                    if slice.len().is_multiple_of(i) {
+                        // let mid = slice.len() / i;
+                        let mut chunks = slice.chunks_exact(i);
+                        let start = chunks.next().unwrap();
+
+                        for next in chunks {
+                            if next != start {
+                                continue 'length_loop;
+                            }
+                        }
+
+                        invalidsum += n;
+
+                        continue 'numbers_loop;
+                    }
+                }
+            }
+        }
+
+        invalidsum
+    }
+
+    pub fn pux_sieve(&mut self) -> u64 {
+        self.normalize_ranges_log10();
+
+        let mut witness_me = HashSet::new();
+        let mut invalidsum = 0;
+
+        for range in self.ranges.iter() {
+            let lower_bound = *range.start();
+            let upper_bound = *range.end();
+
+            assert!(1 <= lower_bound && lower_bound <= upper_bound);
+
+            let bound_digits = Self::count_digits(lower_bound);
+
+            assert_eq!(bound_digits, Self::count_digits(upper_bound));
+
+            for piece_digits in 1..(bound_digits / 2 + 1) {
+                if !bound_digits.is_multiple_of(piece_digits) {
+                    continue
+                }
+
+                let num_pieces = bound_digits / piece_digits;
+                let mut lower_bound_pieces = split_pieces(lower_bound, piece_digits);
+                let mut lower_piece_bound = lower_bound_pieces.next().unwrap();
+
+                for next_piece in lower_bound_pieces {
+                    if lower_piece_bound == next_piece {
+                        continue
+                    }
+
+                    if lower_piece_bound < next_piece {
+                        lower_piece_bound += 1
+                    }
+
+                    break
+                }
+
+                let mut upper_bound_pieces = split_pieces(upper_bound, piece_digits);
+                let mut upper_piece_bound = upper_bound_pieces.next().unwrap();
+
+                for next_piece in upper_bound_pieces {
+                    if upper_piece_bound == next_piece {
+                        continue
+                    }
+
+                    if upper_piece_bound > next_piece {
+                        upper_piece_bound -= 1
+                    }
+
+                    break
+                }
+
+                if lower_piece_bound > upper_piece_bound {
+                    continue
+                }
+
+                let piece_divisor = 10u64.pow(piece_digits);
+                let mut delta = 1;
+
+                for _ in 0..(num_pieces - 1) {
+                    delta = (delta * piece_divisor) + 1;
+                }
+
+                let mut invalid_product_id = delta * lower_piece_bound;
+
+                assert!(lower_bound <= invalid_product_id && invalid_product_id <= upper_bound);
+
+                if !witness_me.contains(&invalid_product_id) {
+                    invalidsum += invalid_product_id;
+                    witness_me.insert(invalid_product_id);
+                }
+
+                for _ in (lower_piece_bound + 1)..=upper_piece_bound {
+                    invalid_product_id += delta;
+
+                    assert!(lower_bound <= invalid_product_id && invalid_product_id <= upper_bound);
+                    if !witness_me.contains(&invalid_product_id) {
+                        invalidsum += invalid_product_id;
+                        witness_me.insert(invalid_product_id);
+
+                    }
+                }
+            }
+        }
+
+        invalidsum
+    }
+}
+
+impl Solution for Elfilter {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Elfilter::parse(input)
+    }
+
+    fn part1(&self) -> anyhow::Result<u64> {
+        Ok(self.simple_sieve())
+    }
+
+    fn part2(&self) -> anyhow::Result<u64> {
+        Ok(self.clone().pux_sieve())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut ranges: Vec<RangeInclusive<u64>> = vec![];
+
+        // IDs of every length up to 10 digits, but the ranges stay narrow like the real ones and never overlap
+        while ranges.len() < size {
+            let digits = rng.random_range(1..=10);
+            let first = rng.random_range(10u64.pow(digits - 1)..10u64.pow(digits));
+            let range = first..=first + rng.random_range(0..10_000);
+
+            if !ranges.iter().any(|r| r.start() <= range.end() && range.start() <= r.end()) {
+                ranges.push(range);
+            }
+        }
+
+        ranges.shuffle(rng);
+
+        let ranges: Vec<_> = ranges.iter().map(|r| format!("{}-{}", r.start(), r.end())).collect();
+
+        ranges.join(",") + "\n"
+    }
+
+    const ITEM: &'static str = "range";
+
+    /// Every new range is checked against all the others, which gets slow well before it runs out of room
+    const MAX_SIZE: usize = 20_000;
+
+    fn variants(part: Part) -> &'static [Variant<Self>] {
+        match part {
+            Part::One => &[
+                Variant { name: "simple", items: |e| e.each_range().map(|e| e.simple_sieve()).collect() },
+            ],
+            Part::Two => &[
+                Variant { name: "repeat", items: |e| e.each_range().map(|e| e.repeat_sieve()).collect() },
+                Variant { name: "pux", items: |e| e.each_range().map(|mut e| e.pux_sieve()).collect() },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day2/example.txt");
+
+    #[test]
+    fn example() {
+        let filter = Elfilter::parse(EXAMPLE).unwrap();
+
+        assert_eq!(filter.simple_sieve(), 1227775554);
+    }
+
+    #[test]
+    fn example_part2() {
+        let filter = Elfilter::parse(EXAMPLE).unwrap();
+
+        assert_eq!(filter.repeat_sieve(), 4174379265);
+    }
+
+    #[test]
+    fn example_pux() {
+        let mut filter = Elfilter::parse(EXAMPLE).unwrap();
+
+        assert_eq!(filter.pux_sieve(), 4174379265);
+    }
+
+    /// Ranges starting around numbers made of a repeated piece, where the invalid IDs are
+    fn near_repeats() -> impl Strategy<Value = RangeInclusive<u64>> {
+        (1..1000u64, 2..=5u32, -2000..2000i64, 0..3000u64).prop_map(|(piece, repeats, offset, width)| {
+            let repeated = (0..repeats).fold(0, |n, _| n * 10u64.pow(Elfilter::count_digits(piece)) + piece);
+            let start = repeated.saturating_add_signed(offset).max(1);
+
+            start..=start + width
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn pux_agrees_with_repeat(range in prop_oneof![near_repeats(), (1..10_000_000_000u64, 0..3000u64).prop_map(|(s, w)| s..=s + w)]) {
+            let filter = Elfilter { ranges: vec![range] };
+
+            prop_assert_eq!(filter.clone().pux_sieve(), filter.repeat_sieve());
+        }
+    }
+
+    #[test]
+    fn ids_past_19_digits() {
+        let err = Elfilter::parse("11-22,9999999999999999990-10000000000000000000").err().unwrap();
+
+        assert_eq!(err.to_string(), "line 1, column 27: expected an ID of at most 9999999999999999999, found \"10000000000000000000\"");
+
+        let mut filter = Elfilter::parse("9999999999999999990-9999999999999999999").unwrap();
+
+        assert_eq!(filter.pux_sieve(), 9999999999999999999);
+    }
+
+    #[test]
+    fn splits() {
+        assert_eq!(split_pieces(123456, 1).collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(split_pieces(123456, 2).collect::<Vec<_>>(), vec![12, 34, 56]);
+        assert_eq!(split_pieces(123456, 3).collect::<Vec<_>>(), vec![123, 456]);
+    }
+}
@@ -0,0 +1,273 @@
+use std::{fmt::Write, sync::atomic::AtomicUsize};
+
+use rand::{Rng as _, rngs::StdRng};
+
+use crate::{deadline::{Deadline, Timeout, Unresolved}, parse::{self, ParseError}, runner::Part, solution::{Solution, Variant}};
+
+#[allow(unused)]
+static SER_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+pub struct Lobby {
+    banks: Vec<Vec<u8>>
+}
+
+impl Lobby {
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let banks = parse::lines(s)
+            .map(|mut line| {
+                let mut bank = vec![];
+
+                while !line.is_empty() {
+                    let c = line.char_of("0123456789", "a battery joltage digit")?;
+
+                    bank.push(c.to_digit(10).unwrap() as u8);
+                }
+
+                // Part 2 turns on 12 of them
+                if bank.len() < 12 {
+                    return Err(line.error("at least 12 batteries in the bank"));
+                }
+
+                Ok(bank)
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Self { banks })
+    }
+
+    pub fn joltages(&self) -> impl Iterator<Item = u32> {
+        self.banks.iter().map(|bank| {
+            // Let's keep it simple, obviously it'd start with the largest digit and the max length
+
+            let mut buf = String::new();
+            let mut biggest = 0;
+
+            for i in 0..bank.len() {
+                for j in (i + 1)..bank.len() {
+                    let a = bank[i];
+                    let b = bank[j];
+
+                    buf.clear();
+
+                    write!(buf, "{a}{b}").unwrap();
+
+                    let c = buf.parse().unwrap();
+
+                    if c > biggest {
+                        biggest = c;
+                    }
+                }
+            }
+
+            biggest
+        })
+    }
+
+    /// Runs within an order of magnitude of the lifetime of the universe, so banks that run out of
+    /// `--timeout` are left unresolved (and come out as 0)
+    pub fn joltages_schlonger(&self) -> impl Iterator<Item = u64> {
+        let unresolved = Unresolved::here();
+
+        self.banks.iter().enumerate().map(move |(index, bank)| {
+            Self::schlonger(bank, &Deadline::per_item()).unwrap_or_else(|Timeout| {
+                unresolved.note(index);
+
+                0
+            })
+        })
+    }
+
+    fn schlonger(bank: &[u8], deadline: &Deadline) -> Result<u64, Timeout> {
+        const MAX: u64 = 12;
+
+        let mut buf = String::new();
+        let end = (bank.len() - 1) as u64;
+
+        let mut indices: Vec<_> = (0..MAX).collect();
+
+        indices.iter().for_each(|&i| write!(buf, "{}", bank[i as usize]).unwrap());
+
+        let mut biggest = buf.parse().unwrap();
+
+        // Brute force algo
+
+        'outer:
+        for tries in 0u64.. {
+            if tries.is_multiple_of(4096) {
+                deadline.check()?;
+            }
+
+            for i in (0..MAX).rev() {
+                let iu = i as usize;
+                if indices[iu] == end {
+                    continue;
+                }
+
+                let next = indices[iu] + 1;
+                if indices[iu..].contains(&next) {
+                    continue;
+                }
+
+                indices[iu] = next;
+
+                for (j, u) in indices.iter_mut().enumerate().take(MAX as usize).skip(iu + 1) {
+                    *u = next + j as u64 - i;
+                }
+
+                buf.clear();
+                indices.iter().for_each(|&i| write!(buf, "{}", bank[i as usize]).unwrap());
+
+                let parsed = buf.parse().unwrap();
+
+                // println!("{indices:?}");
+
+                if parsed > biggest {
+                    log::debug!("new biggest: {parsed} {indices:?}");
+                    biggest = parsed;
+                }
+
+                continue 'outer;
+            }
+
+            log::debug!("finished one loop {biggest}");
+
+            break;
+        }
+
+        Ok(biggest)
+    }
+
+    pub fn joltages_smarter(&self) -> impl Iterator<Item = u64> {
+        const MAX: usize = 12;
+
+        fn explore_range(shifty: u64, bank: &[u8], start: usize, remaining: usize) -> u64 {
+            // eprintln!("{start} {remaining} {:?}", start..=(bank.len() - remaining));
+            let max_value = bank[start..=(bank.len() - remaining)].iter().cloned().max().unwrap();
+
+            let max_indices = bank.iter().enumerate()
+                .skip(start)
+                .filter_map(|(i, n)| (*n == max_value && i + remaining <= bank.len()).then_some(i));
+
+            // assert!(!max_indices.is_empty());
+
+            let mut biggest = 0;
+
+            for max_index in max_indices {
+                let shiftier = shifty * 10 + bank[max_index] as u64;
+
+                let result = if remaining == 1 {
+                    // SER_COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+
+                    shiftier
+                } else {
+                    explore_range(shiftier, bank, max_index + 1, remaining - 1)
+                };
+
+                if result > biggest {
+                    biggest = result;
+                }
+            }
+
+            biggest
+        }
+
+        self.banks.iter().map(|bank| {
+            explore_range(0, bank, 0, MAX)
+        })
+    }
+}
+
+impl Solution for Lobby {
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Lobby::parse(input)
+    }
+
+    fn part1(&self) -> anyhow::Result<u32> {
+        Ok(self.joltages().sum())
+    }
+
+    fn part2(&self) -> anyhow::Result<u64> {
+        // dbg!(SER_COUNT.load(std::sync::atomic::Ordering::Relaxed));
+
+        Ok(self.joltages_smarter().sum())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        (0..size).map(|_| {
+            let batteries = rng.random_range(12..=100);
+            let mut bank: String = (0..batteries).map(|_| char::from(b'0' + rng.random_range(1..=9))).collect();
+
+            bank.push('\n');
+            bank
+        }).collect()
+    }
+
+    const ITEM: &'static str = "bank";
+
+    fn variants(part: Part) -> &'static [Variant<Self>] {
+        match part {
+            Part::One => &[
+                Variant { name: "joltages", items: |l| l.joltages().map(u64::from).collect() },
+            ],
+            Part::Two => &[
+                Variant { name: "schlonger", items: |l| l.joltages_schlonger().collect() },
+                Variant { name: "smarter", items: |l| l.joltages_smarter().collect() },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day3/example.txt");
+
+    #[test]
+    fn example() {
+        let lobby = Lobby::parse(EXAMPLE).unwrap();
+
+        assert_eq!(lobby.joltages().collect::<Vec<_>>(), vec![98, 89, 78, 92]);
+        assert_eq!(lobby.joltages().sum::<u32>(), 357);
+    }
+
+    #[test]
+    fn example_part2() {
+        let lobby = Lobby::parse(EXAMPLE).unwrap();
+
+        assert_eq!(lobby.joltages_smarter().collect::<Vec<_>>(), vec![987654321111, 811111111119, 434234234278, 888911112111]);
+        assert_eq!(lobby.joltages_smarter().sum::<u64>(), 3121910778619);
+    }
+
+    #[test]
+    fn schlonger_gives_up() {
+        let bank: Vec<u8> = (0..40).map(|i| i % 9 + 1).collect();
+
+        assert_eq!(Lobby::schlonger(&bank, &Deadline::after(std::time::Duration::ZERO)), Err(Timeout));
+        assert_eq!(Lobby::schlonger(&bank[..13], &Deadline::never()), Ok(Lobby { banks: vec![bank[..13].to_vec()] }.joltages_smarter().next().unwrap()));
+    }
+
+    /// Every way of picking 12 batteries, which is only bearable for banks a few batteries longer than that
+    fn exhaustive(bank: &[u8]) -> u64 {
+        (0u32..1 << bank.len())
+            .filter(|picked| picked.count_ones() == 12)
+            .map(|picked| (0..bank.len()).filter(|i| picked & 1 << i != 0).fold(0, |n, i| n * 10 + bank[i] as u64))
+            .max()
+            .unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn smarter_agrees_with_exhaustive(bank in prop::collection::vec(0..=9u8, 12..=16)) {
+            let lobby = Lobby { banks: vec![bank.clone()] };
+
+            prop_assert_eq!(lobby.joltages_smarter().next(), Some(exhaustive(&bank)));
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use anyhow::bail;
+use rand::{Rng as _, rngs::StdRng};
+
+use crate::{grid::Grid, recorder::{Recorder, Rgb}, solution::Solution};
+
+#[derive(Clone)]
+pub struct Printing {
+    rolls: Grid<bool>
+}
+
+impl Printing {
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let rolls = Grid::parse(s, &[('@', true), ('.', false)])?;
+
+        if rolls.is_empty() {
+            bail!("there's no grid to speak of");
+        }
+
+        Ok(Self { rolls })
+    }
+
+    pub fn accessible_rolls(&self) -> impl Iterator<Item = (usize, usize)> {
+        self.rolls.cells().filter_map(move |((x, y), is_roll)| {
+            if *is_roll {
+                let adjacent_count = self.rolls.neighbours8(x, y).filter(|&p| self.rolls[p]).count();
+
+                if adjacent_count < 4 {
+                    return Some((x, y));
+                }
+            }
+
+            None
+        })
+    }
+
+    pub fn count_accessible_rolls(&self) -> u32 {
+        self.accessible_rolls().count() as u32
+    }
+
+    pub fn remove_cycles_rolls(&mut self) -> u32 {
+        self.remove_cycles_rolls_watched(|_, _| ())
+    }
+
+    /// The same, showing `watch` the rolls before each wave along with the ones it's about to take away,
+    /// and once more at the end when there's nothing left to take
+    pub fn remove_cycles_rolls_watched(&mut self, mut watch: impl FnMut(&Grid<bool>, &[(usize, usize)])) -> u32 {
+        let mut rolls: Vec<_>;
+        let mut removed_count = 0;
+
+        loop {
+            rolls = self.accessible_rolls().collect();
+
+            watch(&self.rolls, &rolls);
+
+            if rolls.is_empty() {
+                break;
+            }
+
+            removed_count += rolls.len() as u32;
+
+            for p in rolls {
+                self.rolls[p] = false;
+            }
+        }
+
+        removed_count
+    }
+}
+
+impl Solution for Printing {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Printing::parse(input)
+    }
+
+    fn part1(&self) -> anyhow::Result<u32> {
+        Ok(self.count_accessible_rolls())
+    }
+
+    fn part2(&self) -> anyhow::Result<u32> {
+        Ok(self.clone().remove_cycles_rolls())
+    }
+
+    /// A square that side is already 100M cells
+    const MAX_SIZE: usize = 10_000;
+
+    /// A `size` by `size` square
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let size = size.max(1);
+
+        Grid::from_fn(size, size, |_, _| if rng.random_bool(0.6) { '@' } else { '.' }).to_string()
+    }
+
+    /// Every removal wave, with the rolls about to go in red
+    fn record(&self, recorder: &mut Recorder) {
+        const FLOOR: Rgb = [24, 24, 32];
+        const ROLL: Rgb = [200, 200, 200];
+        const LEAVING: Rgb = [230, 60, 50];
+
+        self.clone().remove_cycles_rolls_watched(|rolls, leaving| {
+            let mut frame = rolls.map(|&is_roll| if is_roll { ROLL } else { FLOOR });
+
+            for &p in leaving {
+                frame[p] = LEAVING;
+            }
+
+            recorder.push(frame);
+        });
+    }
+}
+
+#[cfg(test)]
+#[allow(unused)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day4/example.txt");
+
+    #[test]
+    fn example() {
+        let printing = Printing::parse(EXAMPLE).unwrap();
+
+        assert_eq!(printing.count_accessible_rolls(), 13);
+    }
+
+    #[test]
+    fn example_part2() {
+        let mut printing = Printing::parse(EXAMPLE).unwrap();
+
+        assert_eq!(printing.remove_cycles_rolls(), 43);
+    }
+
+    #[test]
+    fn recorded_waves() {
+        let printing = Printing::parse(EXAMPLE).unwrap();
+        let mut waves = vec![];
+
+        printing.clone().remove_cycles_rolls_watched(|_, leaving| waves.push(leaving.len()));
+
+        let mut recorder = Recorder::new();
+        printing.record(&mut recorder);
+
+        // A frame per wave and the one where nothing more can go
+        assert_eq!(recorder.len(), waves.len());
+        assert_eq!(waves.last(), Some(&0));
+        assert_eq!(waves.iter().sum::<usize>(), 43);
+        assert_eq!(recorder.frames()[0].cells().filter(|(_, colour)| **colour == [230, 60, 50]).count(), 13);
+    }
+}
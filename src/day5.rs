@@ -0,0 +1,164 @@
+use std::{collections::HashSet, ops::RangeInclusive};
+
+use anyhow::bail;
+use rand::{Rng as _, rngs::StdRng};
+
+use crate::{parse, solution::Solution};
+
+pub struct Cafeteria {
+    ranges: Vec<RangeInclusive<usize>>,
+    ingredients: Vec<usize>
+}
+
+impl Cafeteria {
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let mut lines = parse::lines(s);
+        let mut ranges = Vec::new();
+        let mut ingredients = Vec::new();
+
+        loop {
+            match lines.next() {
+                Some(line) if line.is_empty() => break,
+                Some(mut line) => {
+                    let range_start = line.clone();
+                    let start: usize = line.number()?;
+                    line.expect("-")?;
+                    let end: usize = line.number()?;
+                    line.end()?;
+
+                    // Flattening looks one ID below every start
+                    if start == 0 || start > end {
+                        return Err(range_start.error("a range of positive IDs going upwards").into());
+                    }
+
+                    ranges.push(start..=end);
+                }
+                None => bail!("But we haven't even gotten to the ingredients! (expected a blank line after the ranges)"),
+            }
+        }
+
+        for mut line in lines {
+            ingredients.push(line.number()?);
+
+            line.end()?;
+        }
+
+        Ok(Cafeteria { ranges, ingredients })
+    }
+
+    pub fn fresh_ingredient_count(&self) -> usize {
+        self.ingredients.iter().filter(|ing| self.ranges.iter().any(|range| range.contains(ing))).count()
+    }
+
+    pub fn flatten_ranges(&self) -> HashSet<RangeInclusive<usize>> {
+        let mut flattened_ranges = HashSet::new();
+        let mut edges: Vec<_> = self.ranges.iter()
+            .flat_map(|r| [*r.start(), *r.start() - 1, *r.end() - 1, *r.end()])
+            .collect();
+
+        edges.sort();
+
+        // 1-10
+        // 3-4
+        // -> 1-2, 3-4, 5-10
+        // 10-20
+        // 5-12
+        // -> 5-9, 10-12, 13-20
+        // 1-2
+        // 1-2
+
+        // Actually, I don't know how it works
+
+        for range in &self.ranges {
+            let mut start = *range.start();
+            for edge in edges.iter().cloned() {
+                if range.contains(&edge) && start <= edge {
+                    let flat = start..=edge;
+                    // dbg!(&flat);
+                    flattened_ranges.insert(flat);
+                    start = edge + 1;
+                }
+            }
+
+            if start <= *range.end() {
+                flattened_ranges.insert(start..=*range.end());
+            }
+        }
+
+        flattened_ranges
+    }
+
+    pub fn all_fresh_count(&self) -> usize {
+        let flattened_ranges = self.flatten_ranges();
+
+        flattened_ranges.iter().map(|r| r.end() - r.start() + 1).sum()
+    }
+}
+
+impl Solution for Cafeteria {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Cafeteria::parse(input)
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        Ok(self.fresh_ingredient_count())
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        Ok(self.all_fresh_count())
+    }
+
+    /// `size` ranges (overlapping each other as they please) and as many ingredients, about half of them fresh
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let ranges: Vec<_> = (0..size).map(|_| {
+            let start = rng.random_range(1..1_000_000_000_000usize);
+
+            start..=start + rng.random_range(0..10_000_000_000)
+        }).collect();
+
+        let mut s: String = ranges.iter().map(|r| format!("{}-{}\n", r.start(), r.end())).collect();
+
+        s.push('\n');
+
+        for _ in 0..size {
+            let ingredient = if rng.random() && !ranges.is_empty() {
+                let range = ranges[rng.random_range(0..ranges.len())].clone();
+
+                rng.random_range(range)
+            } else {
+                rng.random_range(1..1_010_000_000_000)
+            };
+
+            s += &format!("{ingredient}\n");
+        }
+
+        s
+    }
+}
+
+#[cfg(test)]
+#[allow(unused)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day5/example.txt");
+
+    #[test]
+    fn example() {
+        let cafeteria = Cafeteria::parse(EXAMPLE).unwrap();
+
+        assert_eq!(cafeteria.ranges.len(), 4);
+        assert_eq!(cafeteria.ingredients.len(), 6);
+        assert_eq!(cafeteria.fresh_ingredient_count(), 3);
+    }
+
+    #[test]
+    fn example_part2() {
+        let cafeteria = Cafeteria::parse(EXAMPLE).unwrap();
+
+        assert_eq!(cafeteria.all_fresh_count(), 14);
+    }
+}
@@ -0,0 +1,324 @@
+use std::collections::HashSet;
+
+use anyhow::{Context as _, bail};
+use rand::{Rng as _, rngs::StdRng};
+
+use crate::{parse::{self, Cursor, ParseError}, solution::Solution};
+
+pub struct Cephalopostulate {
+    // Keeping as unfancy strings because pux spoiled fuckery in the parsing
+    rows: Vec<Vec<String>>,
+    // Both readings get checked while parsing, so they're kept around: numbers[row][problem]...
+    numbers: Vec<Vec<u64>>,
+    // ...and columns[problem][column], read top to bottom
+    columns: Vec<Vec<u64>>
+}
+
+impl Cephalopostulate {
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let line_count = s.lines().count();
+
+        if line_count < 2 {
+            bail!("expected at least one row of numbers and a row of operators");
+        }
+
+        for mut line in parse::lines(s) {
+            let (allowed, expected) = if line.line() == line_count {
+                ("*+ ", "an operator (* or +)")
+            } else {
+                ("0123456789 ", "a digit")
+            };
+
+            while !line.is_empty() {
+                line.char_of(allowed, expected)?;
+            }
+        }
+
+        // You won't catch me dead trying to do it in one pass
+        let mut whitespace_indices = HashSet::new();
+        let mut non_whitespace_indices = HashSet::new();
+
+        for line in s.lines() {
+            line.char_indices().for_each(|(i, c)| {
+                if c.is_ascii_whitespace() {
+                    whitespace_indices.insert(i);
+                } else {
+                    non_whitespace_indices.insert(i);
+                }
+            });
+        }
+
+        let mut spacers: Vec<_> = whitespace_indices.difference(&non_whitespace_indices).cloned().collect();
+        spacers.sort();
+
+        let rows: Vec<Vec<String>> = s.lines().enumerate().map(|(index, line)| {
+            let mut sections = vec![];
+            let mut start = 0;
+
+            for spacer in spacers.iter().cloned() {
+                let Some(section) = line.get(start..spacer) else {
+                    bail!("line {}: expected the row to be padded up to column {}", index + 1, spacer + 1);
+                };
+
+                sections.push(section.to_owned());
+
+                start = spacer + 1;
+            }
+
+            if line.len() > start {
+                sections.push(line[start..line.len()].to_owned());
+            }
+
+            Ok(sections)
+        }).collect::<anyhow::Result<_>>()?;
+
+        let problems = rows[0].len();
+
+        for (index, row) in rows.iter().enumerate() {
+            let expected = if index == line_count - 1 { 1..=1 } else { 1..=usize::MAX };
+
+            if row.len() != problems || !row.iter().all(|section| expected.contains(&section.trim().len())) {
+                bail!("line {}: expected {problems} problems, each with a number (or one operator at the bottom)", index + 1);
+            }
+        }
+
+        let number_rows = &rows[..line_count - 1];
+
+        let numbers = number_rows.iter().enumerate().map(|(index, row)| {
+            let mut start = 0;
+
+            row.iter().map(|section| {
+                let number = Self::section_number(index + 1, start, section);
+
+                start += section.len() + 1;
+
+                number
+            }).collect()
+        }).collect::<Result<_, ParseError>>()?;
+
+        let mut start = 0;
+        let columns = (0..problems).map(|problem| {
+            let width = number_rows.iter().map(|row| row[problem].len()).max().unwrap();
+            let column = (0..width).map(|offset| Self::column_number(number_rows, problem, start, offset)).collect();
+
+            start += width + 1;
+
+            column
+        }).collect::<Result<_, ParseError>>()?;
+
+        Ok(Self { rows, numbers, columns })
+    }
+
+    /// The one number a problem's section of a row holds, with `start` being the section's 0-based column in the line
+    fn section_number(line: usize, start: usize, section: &str) -> Result<u64, ParseError> {
+        let mut cursor = Cursor::new(line, section);
+
+        cursor.skip_whitespace();
+
+        let number = cursor.number().and_then(|number| {
+            cursor.skip_whitespace();
+
+            if cursor.is_empty() {
+                Ok(number)
+            } else {
+                Err(cursor.error("a single number per problem"))
+            }
+        });
+
+        // The input's all digits and spaces by now, so bytes are columns
+        number.map_err(|mut error| {
+            error.column += start;
+            error
+        })
+    }
+
+    /// The number a problem's `offset`th column spells top to bottom, which has to be one run of digits
+    fn column_number(rows: &[Vec<String>], problem: usize, start: usize, offset: usize) -> Result<u64, ParseError> {
+        let error = |index: usize, found: &str, expected: &str| ParseError {
+            line: index + 1,
+            column: start + offset + 1,
+            expected: expected.to_owned(),
+            found: found.to_owned(),
+        };
+
+        let mut digits = String::new();
+        let mut gap = None;
+
+        for (index, row) in rows.iter().enumerate() {
+            match row[problem].get(offset..(offset + 1)) {
+                Some(" ") if !digits.is_empty() => gap = gap.or(Some(index)),
+                Some(digit) if digit != " " => {
+                    if let Some(gap) = gap {
+                        return Err(error(gap, " ", "a digit, so the column reads as a single number"));
+                    }
+
+                    digits.push_str(digit);
+                },
+                _ => {},
+            }
+        }
+
+        if digits.is_empty() {
+            return Err(error(rows.len() - 1, " ", "a digit somewhere in the column"));
+        }
+
+        digits.parse().map_err(|_| error(0, &digits, "a number that fits in 64 bits"))
+    }
+
+    /// Each problem's answer, None where it doesn't fit a u64
+    pub fn solved(&self) -> impl Iterator<Item = Option<u64>> {
+        let length = self.rows[0].len();
+
+        (0..length).map(|cell_index| {
+            let op = self.rows.last().unwrap()[cell_index].trim();
+
+            Self::work_out(op, self.numbers.iter().map(|row| row[cell_index]))
+        })
+    }
+
+    pub fn solved_what_the_fuck_is_cephalopod_math(&self) -> impl Iterator<Item = Option<u64>> {
+        let length = self.rows[0].len();
+
+        (0..length).map(move |cell_index| {
+            let op = self.rows.last().unwrap()[cell_index].trim();
+
+            Self::work_out(op, self.columns[cell_index].iter().cloned())
+        })
+    }
+
+    fn work_out(op: &str, mut numbers: impl Iterator<Item = u64>) -> Option<u64> {
+        let first = numbers.next()?;
+
+        numbers.try_fold(first, |acc, num| if op == "*" {
+            acc.checked_mul(num)
+        } else {
+            acc.checked_add(num)
+        })
+    }
+
+    /// The answers added up, or which one got too big
+    fn total(answers: impl Iterator<Item = Option<u64>>) -> anyhow::Result<u64> {
+        answers.enumerate().try_fold(0u64, |sum, (index, answer)| {
+            let Some(answer) = answer else {
+                bail!("problem {} comes to more than a u64 holds", index + 1);
+            };
+
+            sum.checked_add(answer).context("the answers add up to more than a u64 holds")
+        })
+    }
+}
+
+impl Solution for Cephalopostulate {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Cephalopostulate::parse(input)
+    }
+
+    fn part1(&self) -> anyhow::Result<u64> {
+        Self::total(self.solved())
+    }
+
+    fn part2(&self) -> anyhow::Result<u64> {
+        Self::total(self.solved_what_the_fuck_is_cephalopod_math())
+    }
+
+    /// `size` problems of two to four numbers, up to three digits each so that neither way of reading them overflows
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let numbers = rng.random_range(2..=4);
+        let mut lines = vec![String::new(); numbers + 1];
+
+        for problem in 0..size.max(1) {
+            let mut column: Vec<String> = (0..numbers).map(|_| rng.random_range(1..1000).to_string()).collect();
+            // Reading top to bottom mustn't run into a gap between digits, so the short ones stay together
+            column.sort_by_key(String::len);
+
+            if rng.random() {
+                column.reverse();
+            }
+
+            let width = column.iter().map(String::len).max().unwrap();
+            let right_aligned = rng.random();
+
+            for (line, number) in lines.iter_mut().zip(&column) {
+                if problem > 0 {
+                    line.push(' ');
+                }
+
+                if right_aligned {
+                    *line += &format!("{number:>width$}");
+                } else {
+                    *line += &format!("{number:<width$}");
+                }
+            }
+
+            let operator = if rng.random() { '*' } else { '+' };
+
+            if problem > 0 {
+                lines[numbers].push(' ');
+            }
+
+            lines[numbers] += &format!("{operator:<width$}");
+        }
+
+        lines.iter().map(|line| format!("{line}\n")).collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(unused)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day6/example.txt");
+
+    #[test]
+    fn example() {
+        let ceph = Cephalopostulate::parse(EXAMPLE).unwrap();
+
+        assert_eq!(ceph.solved().collect::<Vec<_>>(), vec![
+            Some(33210),
+            Some(490),
+            Some(4243455),
+            Some(401),
+        ]);
+    }
+
+    #[test]
+    fn example_part2() {
+        let ceph = Cephalopostulate::parse(EXAMPLE).unwrap();
+
+        assert_eq!(ceph.solved_what_the_fuck_is_cephalopod_math().collect::<Vec<_>>(), vec![
+            Some(8544),
+            Some(625),
+            Some(3253600),
+            Some(1058),
+        ]);
+    }
+
+    #[test]
+    fn too_big_for_a_u64() {
+        let ceph = Cephalopostulate::parse("9999999999 1\n9999999999 2\n*          + \n").unwrap();
+
+        assert_eq!(ceph.part1().unwrap_err().to_string(), "problem 1 comes to more than a u64 holds");
+
+        let ceph = Cephalopostulate::parse("18446744073709551615 1\n*                    + \n").unwrap();
+
+        assert_eq!(ceph.part1().unwrap_err().to_string(), "the answers add up to more than a u64 holds");
+    }
+
+    #[test]
+    fn gaps_are_parse_errors() {
+        let error = Cephalopostulate::parse("1 3\n456\n+  \n").err().unwrap();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+
+        assert_eq!((error.line, error.column), (1, 3));
+
+        // Fine read across, but the first column reads 1, a gap, then 3
+        let error = Cephalopostulate::parse("10\n 2\n3 \n+ \n").err().unwrap();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
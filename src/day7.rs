@@ -0,0 +1,306 @@
+use std::fmt::Write;
+
+use anyhow::bail;
+use rand::{Rng as _, rngs::StdRng};
+
+use crate::{grid::Grid, parse::ParseError, recorder::{Recorder, Rgb}, solution::Solution};
+
+#[derive(Clone)]
+pub struct TheScientist {
+    rows: Grid<Cell>
+}
+
+#[derive(Clone)]
+enum Cell {
+    Start,
+    Beam(usize),
+    Splitter,
+    Free
+}
+
+impl Cell {
+    const MAP: [(char, Cell); 4] = [('S', Cell::Start), ('|', Cell::Beam(1)), ('^', Cell::Splitter), ('.', Cell::Free)];
+}
+
+impl std::fmt::Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char(match self {
+            Cell::Start => 'S',
+            Cell::Beam(_) => '|',
+            Cell::Splitter => '^',
+            Cell::Free => '.'
+        })
+    }
+}
+
+impl TheScientist {
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let rows = Grid::parse(s, &Cell::MAP)?;
+
+        if rows.is_empty() {
+            bail!("there's no manifold to speak of");
+        }
+
+        let error = |(x, y): (usize, usize), expected: &str, found: &Cell| ParseError {
+            line: y + 1,
+            column: x + 1,
+            expected: expected.to_owned(),
+            found: found.to_string(),
+        };
+
+        let starts: Vec<_> = rows.cells().filter(|(_, cell)| matches!(cell, Cell::Start)).map(|(at, _)| at).collect();
+
+        match starts[..] {
+            [] => bail!("there's no S for the beam to start from"),
+            [_] => {},
+            [_, second, ..] => return Err(error(second, "only the one S", &Cell::Start).into()),
+        }
+
+        // A splitter right next to another would split onto it; on the edge, half the beam just leaves
+        for ((x, y), cell) in rows.cells().filter(|(_, cell)| matches!(cell, Cell::Splitter)) {
+            if matches!(rows.get(x + 1, y), Some(Cell::Splitter)) {
+                return Err(error((x + 1, y), "a gap between splitters", cell).into());
+            }
+        }
+
+        Ok(Self { rows })
+    }
+
+    pub fn step(&mut self) -> (usize, usize) {
+        let mut targets = vec![];
+        let mut sliced = 0;
+        let mut splits = 0;
+
+        for ((x, y), cell) in self.rows.cells() {
+            let raycast = matches!(cell, Cell::Start | Cell::Beam(_));
+
+            if !raycast {
+                continue;
+            }
+
+            if let Some(cell) = self.rows.get(x, y + 1) {
+                if matches!(cell, Cell::Splitter) {
+                    let mut next = vec![];
+                    x.checked_sub(1).into_iter().for_each(|x| next.push((x, y + 1)));
+                    x.checked_add(1).into_iter().for_each(|x| next.push((x, y + 1)));
+
+                    if next.iter().cloned().any(|(x, y)| {
+                        let tgt = self.rows.get(x, y);
+
+                        matches!(tgt, Some(Cell::Free))
+                    }) {
+                        splits += 1;
+                    }
+
+                    targets.extend(next);
+                } else {
+                    targets.push((x, y + 1));
+                }
+            } else {
+                targets.push((x, y + 1));
+            }
+        }
+
+        for (x, y) in targets {
+            if let Some(cell) = self.rows.get_mut(x, y)
+                && matches!(cell, Cell::Free) {
+                *cell = Cell::Beam(1);
+
+                sliced += 1;
+            }
+        }
+
+        (sliced, splits)
+    }
+
+    pub fn stop(&mut self) -> usize {
+        self.stop_watched(|_| ())
+    }
+
+    /// The same, showing `watch` the manifold before the first step and after every one
+    fn stop_watched(&mut self, mut watch: impl FnMut(&Grid<Cell>)) -> usize {
+        let mut splits_total = 0;
+
+        watch(&self.rows);
+
+        loop {
+            let (sliced, splits) = self.step();
+
+            splits_total += splits;
+
+            if sliced == 0 {
+                break;
+            }
+
+            watch(&self.rows);
+        }
+
+        splits_total
+    }
+
+    pub fn quantum_inferiority(&mut self) -> usize {
+        for y in 0..(self.rows.height() - 1) {
+            for x in 0..self.rows.width() {
+                let cell = &self.rows[(x, y)];
+
+                let stax = match cell {
+                    Cell::Start => 1,
+                    Cell::Beam(x) => *x,
+                    _ => continue,
+                };
+
+                let nexts = match self.rows[(x, y + 1)] {
+                    Cell::Splitter => vec![
+                        (x.checked_sub(1), y + 1),
+                        (x.checked_add(1), y + 1)
+                    ],
+                    // A second start would be refused by `parse`, so this is just the beam going down
+                    _ => vec![
+                        (Some(x), y + 1)
+                    ],
+                };
+
+                for next in nexts {
+                    // Off the edge it's gone, and `parse` keeps splitters apart so anything else just stops it
+                    if let (Some(x), y) = next && let Some(cell) = self.rows.get_mut(x, y) {
+                        match cell {
+                            Cell::Beam(stacks) => *stacks += stax,
+                            Cell::Free => *cell = Cell::Beam(stax),
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+
+        self.rows.row(self.rows.height() - 1).iter().fold(0, |sum, cell| if let Cell::Beam(x) = cell { sum + x } else { sum })
+    }
+}
+
+impl std::fmt::Display for TheScientist {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.rows)
+    }
+}
+
+impl Solution for TheScientist {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        TheScientist::parse(input)
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        Ok(self.clone().stop())
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        Ok(self.clone().quantum_inferiority())
+    }
+
+    /// Twice as wide and twice as tall as the size, so this is about 100M cells
+    const MAX_SIZE: usize = 5_000;
+
+    /// `size` rows of splitters under the start, with a row of nothing after each like the real thing
+    ///
+    /// Splitters are sparse enough that the timelines don't come close to doubling every row.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let width = 2 * size + 3;
+
+        let mut rows = Grid::from_fn(width, 2 * size + 2, |x, y| if y == 0 && x == width / 2 { 'S' } else { '.' });
+
+        for y in (2..rows.height()).step_by(2) {
+            let mut x = 1;
+
+            // A splitter right next to another would split onto it
+            while x < width - 1 {
+                if rng.random_bool(0.3) {
+                    rows[(x, y)] = '^';
+                    x += 2;
+                } else {
+                    x += 1;
+                }
+            }
+        }
+
+        rows.to_string()
+    }
+
+    /// The beams going down a row per frame
+    fn record(&self, recorder: &mut Recorder) {
+        const FREE: Rgb = [16, 16, 28];
+        const SPLITTER: Rgb = [240, 200, 40];
+        const START: Rgb = [60, 220, 90];
+        const BEAM: Rgb = [80, 160, 255];
+
+        self.clone().stop_watched(|rows| recorder.push(rows.map(|cell| match cell {
+            Cell::Start => START,
+            Cell::Beam(_) => BEAM,
+            Cell::Splitter => SPLITTER,
+            Cell::Free => FREE,
+        })));
+    }
+}
+
+#[cfg(test)]
+#[allow(unused)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day7/example.txt");
+    const PUX: &str = include_str!("../examples/day7/pux.txt");
+
+    #[test]
+    fn example() {
+        let mut scientist = TheScientist::parse(EXAMPLE).unwrap();
+
+        assert_eq!(scientist.stop(), 21);
+    }
+
+    #[test]
+    fn awkward_manifolds() {
+        let err = TheScientist::parse(".S..\n.^^.\n").err().unwrap();
+
+        assert_eq!(err.to_string(), "line 2, column 3: expected a gap between splitters, found \"^\"");
+
+        let err = TheScientist::parse(".S.\n...\n.S.\n").err().unwrap();
+
+        assert_eq!(err.to_string(), "line 3, column 2: expected only the one S, found \"S\"");
+        assert!(TheScientist::parse("...\n.^.\n").is_err());
+
+        // Both halves of the beam go off the sides
+        assert_eq!(TheScientist::parse("S\n^\n").unwrap().quantum_inferiority(), 0);
+    }
+
+    #[test]
+    fn test_patience_pux() {
+        let mut scienpux = TheScientist::parse(PUX).unwrap();
+
+        assert_eq!(scienpux.stop(), 4);
+    }
+
+    #[test]
+    fn example_part2() {
+        let mut scientist = TheScientist::parse(EXAMPLE).unwrap();
+
+        assert_eq!(scientist.quantum_inferiority(), 40);
+    }
+
+    #[test]
+    fn recorded_beams() {
+        let scientist = TheScientist::parse(EXAMPLE).unwrap();
+        let mut recorder = Recorder::new();
+
+        scientist.record(&mut recorder);
+
+        // The start, then a frame for each row the beams get down to
+        assert_eq!(recorder.len(), scientist.rows.height());
+
+        let mut stopped = scientist.clone();
+        stopped.stop();
+        let beams = stopped.rows.cells().filter(|(_, cell)| matches!(cell, Cell::Beam(_))).count();
+
+        assert_eq!(recorder.frames().last().unwrap().cells().filter(|(_, colour)| **colour == [80, 160, 255]).count(), beams);
+    }
+}
@@ -0,0 +1,369 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::bail;
+use rand::{Rng as _, rngs::StdRng};
+
+use crate::{parse::{self, Cursor, ParseError}, solution::Solution};
+
+#[derive(Clone)]
+pub struct WeebsLastTour {
+    boxes: Vec<Box>,
+    last_circuit: usize,
+    // circuits is a hashmap because they can appear and disappear when merging
+    circuits: HashMap<usize, Circuit>,
+    circuits_map: HashMap<usize, usize>,
+    hitchings: HashSet<(usize, usize)>,
+    nearest_neighbors: HashMap<usize, (usize, f64)>,
+    nearest_neighbor: Option<(usize, f64)>
+}
+
+impl WeebsLastTour {
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let boxes: Vec<_> = parse::lines(s).map(Box::from_cursor).collect::<Result<_, ParseError>>()?;
+
+        if boxes.len() < 2 {
+            bail!("expected at least two junction boxes to hitch together");
+        }
+
+        let mut us = Self {
+            boxes,
+            last_circuit: 0,
+            circuits: HashMap::new(),
+            circuits_map: HashMap::new(),
+            hitchings: HashSet::new(),
+            nearest_neighbors: HashMap::new(),
+            nearest_neighbor: None
+        };
+
+        us.precompute_nearest_neighbors();
+
+        Ok(us)
+    }
+
+    fn precompute_nearest_neighbors(&mut self) {
+        // Mmm... tasty O(n^2)
+        for i in 0..self.boxes.len() {
+            self.compute_nearest_neighbors(i);
+        }
+    }
+
+    fn compute_nearest_neighbors(&mut self, index: usize) {
+        let mut nearest_match = None;
+        let mut nearest_distance = f64::INFINITY;
+        let a = &self.boxes[index];
+
+        for (j, b) in self.boxes.iter().enumerate() {
+            if index == j {
+                // No selfcest
+                continue;
+            }
+
+            if self.hitchings.contains(&(index, j)) {
+                continue
+            }
+
+            let distance = a.distance_to(b);
+            if distance < nearest_distance {
+                nearest_match = Some(j);
+                nearest_distance = distance;
+
+                if let Some((idx, dist)) = &mut self.nearest_neighbor {
+                    if distance < *dist {
+                        *idx = index;
+                        *dist = distance;
+                    }
+                } else {
+                    self.nearest_neighbor = Some((index, distance));
+                }
+            }
+        }
+
+        // Hitched to everyone already, so nobody's left for it
+        match nearest_match {
+            Some(j) => self.nearest_neighbors.insert(index, (j, nearest_distance)),
+            None => self.nearest_neighbors.remove(&index),
+        };
+    }
+
+    fn get_nearest_neighbor(&self) -> Option<(usize, f64)> {
+        // I'm surprised I don't have a better way to compute this
+        self.nearest_neighbors.values()
+            .max_by(|(_, da), (_, db)| db.total_cmp(da))
+            .cloned()
+    }
+
+    pub fn closest_bachelors(&self) -> Option<(usize, usize)> {
+        let nearest_neighbor = self.get_nearest_neighbor()?.0;
+        let neighbor = self.nearest_neighbors.get(&nearest_neighbor)?.0;
+
+        // Sort for aid in tests
+        if nearest_neighbor < neighbor {
+            Some((nearest_neighbor, neighbor))
+        } else {
+            Some((neighbor, nearest_neighbor))
+        }
+
+        // let mut nearest_match = None;
+        // let mut nearest_distance = f64::INFINITY;
+
+        // // Mmm... tasty O(n^2)
+        // for (i, a) in self.boxes.iter().enumerate() {
+        //     for (j, b) in self.boxes.iter().enumerate() {
+        //         if i == j {
+        //             // No selfcest
+        //             continue;
+        //         }
+
+        //         if self.hitchings.contains(&(i, j)) {
+        //             continue
+        //         }
+
+        //         let distance = a.distance_to(b);
+        //         if distance < nearest_distance {
+        //             nearest_match = Some((i, j));
+        //             nearest_distance = distance;
+        //         }
+        //     }
+        // }
+
+        // if let Some(m) = nearest_match {
+        //     assert_eq!(self.nearest_neighbors.get(&m.0), Some(&m.1));
+        //     assert_eq!(self.nearest_neighbors.get(&m.1), Some(&m.0));
+        // }
+
+        // nearest_match
+    }
+
+    pub fn hitch(&mut self, a: usize, b: usize) {
+        // lazyyyyy...
+        self.hitchings.insert((a, b));
+        self.hitchings.insert((b, a));
+
+        self.nearest_neighbor = None;
+
+        self.compute_nearest_neighbors(a);
+        self.compute_nearest_neighbors(b);
+
+        let acirc = self.circuits_map.get(&a).cloned();
+        let bcirc = self.circuits_map.get(&b).cloned();
+
+        match (acirc, bcirc) {
+            (None, None) => {
+                self.circuits.insert(self.last_circuit, Circuit {
+                    box_indices: HashSet::from([a, b]),
+                });
+
+                self.circuits_map.insert(a, self.last_circuit);
+                self.circuits_map.insert(b, self.last_circuit);
+
+                self.last_circuit += 1;
+            },
+            (None, Some(x)) => {
+                self.circuits.get_mut(&x).unwrap().box_indices.insert(a);
+
+                self.circuits_map.insert(a, x);
+            },
+            (Some(x), None) => {
+                self.circuits.get_mut(&x).unwrap().box_indices.insert(b);
+
+                self.circuits_map.insert(b, x);
+            },
+            (Some(x), Some(y)) if x != y => {
+                let [Some(ac), Some(bc)] = self.circuits.get_disjoint_mut([&x, &y]) else {
+                    panic!();
+                };
+
+                // eeny meeny miny moe... let's kill b's circuit
+                for index in bc.box_indices.iter() {
+                    // Reparent
+                    self.circuits_map.insert(*index, x);
+                    ac.box_indices.insert(*index);
+                }
+
+                self.circuits.remove(&y);
+            },
+            (Some(x), Some(y)) => {
+                // Same-group hitching, just ignore...
+                assert_eq!(x, y);
+                assert!(!self.circuits.get_mut(&x).unwrap().box_indices.insert(a));
+                assert!(!self.circuits.get_mut(&x).unwrap().box_indices.insert(b));
+            },
+        }
+    }
+
+    pub fn were_done(&self) -> bool {
+        self.circuits.len() == 1 && self.circuits.values().next().unwrap().box_indices.len() == self.boxes.len()
+    }
+
+    pub fn get_final_bachs(&mut self) -> (usize, usize) {
+        let final_bachs;
+
+        loop {
+            let bachs = self.closest_bachelors().expect("there's at least two singles like god damn get off my ass");
+
+            self.hitch(bachs.0, bachs.1);
+
+            if self.were_done() {
+                final_bachs = bachs;
+                break;
+            }
+        }
+
+        final_bachs
+    }
+
+    pub fn circuitry_expenses(&self, largest: usize) -> usize {
+        let mut circuits: Vec<_> = self.circuits.values().collect();
+
+        circuits.sort_by_key(|c| std::cmp::Reverse(c.box_indices.len()));
+
+        circuits.iter().take(largest).map(|circuit| circuit.box_indices.len()).for_each(|len| log::debug!("{len}"));
+
+        circuits.iter().take(largest).map(|circuit| circuit.box_indices.len()).product()
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Box {
+    pub x: u64,
+    pub y: u64,
+    pub z: u64
+}
+
+impl Box {
+    pub fn from_line(line: &str) -> Result<Self, ParseError> {
+        Self::from_cursor(Cursor::new(1, line))
+    }
+
+    fn from_cursor(mut line: Cursor) -> Result<Self, ParseError> {
+        // You must be at least a three dimensional being to enter this ride
+        let x = line.number()?;
+        line.expect(",")?;
+        let y = line.number()?;
+        line.expect(",")?;
+        let z = line.number()?;
+        line.end()?;
+
+        Ok(Box { x, y, z })
+    }
+
+    pub fn distance_to(&self, other: &Box) -> f64 {
+        let f: f64 = (self.x.abs_diff(other.x).pow(2) + self.y.abs_diff(other.y).pow(2) + self.z.abs_diff(other.z).pow(2)) as f64;
+
+        f.sqrt()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Circuit {
+    box_indices: HashSet<usize>
+}
+
+impl Solution for WeebsLastTour {
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        WeebsLastTour::parse(input)
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        let mut tour = self.clone();
+
+        // Small inputs run out of pairs before the thousandth connection
+        for _ in 0..1000 {
+            let Some((a, b)) = tour.closest_bachelors() else {
+                break;
+            };
+
+            tour.hitch(a, b);
+            log::trace!("{a} {b}");
+        }
+
+        Ok(tour.circuitry_expenses(3))
+    }
+
+    fn part2(&self) -> anyhow::Result<u64> {
+        let mut tour = self.clone();
+
+        let final_bachs = tour.get_final_bachs();
+
+        Ok(tour.boxes[final_bachs.0].x * tour.boxes[final_bachs.1].x)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        // Part 1 makes 1000 hitches, with much fewer boxes than the real thousand it's all one big circuit (or worse,
+        // a box gets hitched to every other and there's nobody left)
+        (0..size.max(1000)).map(|_| {
+            let [x, y, z] = [(); 3].map(|_| rng.random_range(0..100_000));
+
+            format!("{x},{y},{z}\n")
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(unused)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day8/example.txt");
+
+    #[test]
+    fn example() {
+        let mut tour = WeebsLastTour::parse(EXAMPLE).unwrap();
+        let bachs = tour.closest_bachelors().expect("there's singles");
+
+        assert_eq!(bachs, (0, 19));
+        assert_eq!(tour.boxes[bachs.0], Box::from_line("162,817,812").unwrap());
+        assert_eq!(tour.boxes[bachs.1], Box::from_line("425,690,689").unwrap());
+
+        tour.hitch(bachs.0, bachs.1);
+        // 2j + 18*1j
+
+        let bachs = tour.closest_bachelors().expect("there's still singles");
+
+        assert_eq!(bachs, (0, 7));
+        assert_eq!(tour.boxes[bachs.0], Box::from_line("162,817,812").unwrap());
+        assert_eq!(tour.boxes[bachs.1], Box::from_line("431,825,988").unwrap());
+
+        tour.hitch(bachs.0, bachs.1);
+        // 3j + 17*1j
+
+        let bachs = tour.closest_bachelors().expect("there's still singles");
+
+        assert_eq!(bachs, (2, 13));
+        assert_eq!(tour.boxes[bachs.0], Box::from_line("906,360,560").unwrap());
+        assert_eq!(tour.boxes[bachs.1], Box::from_line("805,96,715").unwrap());
+
+        tour.hitch(bachs.0, bachs.1);
+
+        // 3j + 2j + 15*1j
+
+        // We hitched thrice, do the other 7 for 10 hitchings
+        for i in 0..7 {
+            let bachs = tour.closest_bachelors().expect("there's still singles");
+
+            tour.hitch(bachs.0, bachs.1);
+        }
+
+        assert_eq!(tour.circuitry_expenses(3), 40);
+    }
+
+    #[test]
+    fn part1_runs_out_of_pairs() {
+        // 20 boxes only make 190 pairs, which all end up in the one circuit
+        let tour = WeebsLastTour::parse(EXAMPLE).unwrap();
+
+        assert_eq!(tour.part1().unwrap(), 20);
+    }
+
+    #[test]
+    fn example_part2() {
+        let mut tour = WeebsLastTour::parse(EXAMPLE).unwrap();
+
+        let final_bachs = tour.get_final_bachs();
+
+        assert_eq!(tour.boxes[final_bachs.0].x * tour.boxes[final_bachs.1].x, 25272);
+    }
+}
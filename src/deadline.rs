@@ -0,0 +1,157 @@
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    sync::{Arc, atomic::{AtomicBool, AtomicU64, Ordering}},
+    time::{Duration, Instant},
+};
+
+use crate::capture::{Capture, Slot};
+
+/// What a solver returns when it gave up on an item, because it ran out of time or was told to stop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeout;
+
+impl Display for Timeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("gave up before finding an answer")
+    }
+}
+
+impl std::error::Error for Timeout {}
+
+/// When a long search should give up, checked by the search itself every so often
+///
+/// Clones share the cancellation, so one can be handed to a solver and cancelled from elsewhere.
+#[derive(Debug, Clone, Default)]
+pub struct Deadline {
+    at: Option<Instant>,
+    cancelled: Arc<AtomicBool>,
+}
+
+/// Milliseconds each item gets from [`Deadline::per_item`], 0 for forever
+static PER_ITEM: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// Where [`capture_unresolved`] running on this thread wants the items
+    static CAPTURE: Slot<usize> = const { RefCell::new(Capture::none()) };
+}
+
+/// Where to note the items that were given up on, for whoever is capturing them
+///
+/// Taken on the thread running [`capture_unresolved`] and handed to the threads working for it, since those
+/// don't know about the capture themselves. Captures on different threads each get only their own items.
+#[derive(Debug, Clone, Default)]
+pub struct Unresolved(Capture<usize>);
+
+/// How long solvers get for each item from now on, `None` lets them take forever
+pub fn set_per_item(limit: Option<Duration>) {
+    let millis = limit.map_or(0, |limit| limit.as_millis().clamp(1, u64::MAX as u128) as u64);
+
+    PER_ITEM.store(millis, Ordering::Relaxed);
+}
+
+/// Runs `f`, returning the indices of the items noted through [`Unresolved::here`] meanwhile, in order
+///
+/// A capture inside another one keeps its items to itself.
+pub fn capture_unresolved<T>(f: impl FnOnce() -> T) -> (T, Vec<usize>) {
+    let items = Capture::new();
+    let result = items.within(&CAPTURE, f);
+
+    let mut unresolved = items.take();
+    unresolved.sort();
+    unresolved.dedup();
+
+    (result, unresolved)
+}
+
+impl Unresolved {
+    /// The capture running on this thread, or nowhere if there isn't one
+    pub fn here() -> Self {
+        Self(Capture::current(&CAPTURE))
+    }
+
+    /// Notes that the item at `index` (in input order) was given up on
+    pub fn note(&self, index: usize) {
+        self.0.push(index);
+    }
+}
+
+impl Deadline {
+    /// Only ever stops if cancelled
+    pub fn never() -> Self {
+        Self::default()
+    }
+
+    pub fn after(limit: Duration) -> Self {
+        Self { at: Some(Instant::now() + limit), ..Self::default() }
+    }
+
+    /// A fresh deadline for one item, as far away as `--timeout` says
+    pub fn per_item() -> Self {
+        match PER_ITEM.load(Ordering::Relaxed) {
+            0 => Self::never(),
+            millis => Self::after(Duration::from_millis(millis)),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// How long there's left, `None` if there's no limit
+    pub fn remaining(&self) -> Option<Duration> {
+        self.at.map(|at| at.saturating_duration_since(Instant::now()))
+    }
+
+    pub fn check(&self) -> Result<(), Timeout> {
+        if self.cancelled.load(Ordering::Relaxed) || self.at.is_some_and(|at| Instant::now() >= at) {
+            return Err(Timeout);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deadlines() {
+        assert_eq!(Deadline::never().check(), Ok(()));
+        assert_eq!(Deadline::after(Duration::ZERO).check(), Err(Timeout));
+        assert_eq!(Deadline::after(Duration::from_secs(60)).check(), Ok(()));
+
+        let deadline = Deadline::never();
+        let handed_out = deadline.clone();
+
+        deadline.cancel();
+
+        assert_eq!(handed_out.check(), Err(Timeout));
+    }
+
+    #[test]
+    fn unresolved_items_are_captured() {
+        let ((_, inner), items) = capture_unresolved(|| {
+            let unresolved = Unresolved::here();
+
+            unresolved.note(4);
+            std::thread::spawn(move || unresolved.note(1)).join().unwrap();
+
+            let inner = capture_unresolved(|| Unresolved::here().note(9));
+
+            Unresolved::here().note(4);
+
+            inner
+        });
+
+        assert_eq!(items, [1, 4]);
+        assert_eq!(inner, [9]);
+
+        // Nobody's listening
+        Unresolved::here().note(7);
+        std::thread::spawn(|| Unresolved::here().note(8)).join().unwrap();
+
+        assert!(capture_unresolved(|| ()).1.is_empty());
+    }
+}
@@ -0,0 +1,127 @@
+use std::{fs, path::{Path, PathBuf}};
+
+use anyhow::{Context as _, bail};
+
+use crate::{parse::{self, ParseError}, runner::{self, Part}};
+
+/// Where the examples live, a directory per day (`day6/`) with inputs (`example.txt`) that each have the
+/// answers they should give next to them (`example.answers`)
+pub const EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+
+/// An example input and what it should come out as
+#[derive(Debug)]
+pub struct Fixture {
+    pub day: u8,
+    pub path: PathBuf,
+    pub expected: Vec<(Part, String)>,
+}
+
+/// Every example under `dir`, by day and then by name
+pub fn discover(dir: &Path) -> anyhow::Result<Vec<Fixture>> {
+    let mut fixtures = vec![];
+
+    for entry in fs::read_dir(dir).with_context(|| format!("couldn't list the examples in {}", dir.display()))? {
+        let day_dir = entry?.path();
+        let name = day_dir.file_name().unwrap_or_default().to_string_lossy();
+
+        let Some(day) = name.strip_prefix("day").and_then(|day| day.parse().ok()) else {
+            bail!("{} isn't named after a day, like day6", day_dir.display());
+        };
+
+        for entry in fs::read_dir(&day_dir)? {
+            let path = entry?.path();
+
+            if path.extension().is_none_or(|extension| extension != "txt") {
+                continue;
+            }
+
+            let sidecar = path.with_extension("answers");
+            let answers = fs::read_to_string(&sidecar)
+                .with_context(|| format!("{} has nothing to check it against, it needs a {}", path.display(), sidecar.display()))?;
+            let expected = parse_answers(&answers).with_context(|| format!("in {}", sidecar.display()))?;
+
+            fixtures.push(Fixture { day, path, expected });
+        }
+    }
+
+    fixtures.sort_by(|a, b| (a.day, &a.path).cmp(&(b.day, &b.path)));
+
+    Ok(fixtures)
+}
+
+/// A part and its answer per line, leaving out the parts the example doesn't say anything about
+pub fn parse_answers(s: &str) -> Result<Vec<(Part, String)>, ParseError> {
+    let mut expected = vec![];
+
+    for mut line in parse::lines(s) {
+        if line.is_empty() || line.rest().starts_with('#') {
+            continue;
+        }
+
+        let part = match line.char_of("12", "part 1 or 2")? {
+            '1' => Part::One,
+            _ => Part::Two,
+        };
+
+        line.expect(" ")?;
+
+        match line.rest().trim() {
+            "" => return Err(line.error("an answer")),
+            answer => expected.push((part, answer.to_owned())),
+        }
+    }
+
+    Ok(expected)
+}
+
+impl Fixture {
+    /// What came out differently than expected, as "part 2: expected 40, got 41"
+    pub fn check(&self) -> anyhow::Result<Vec<String>> {
+        let day = runner::find_day(self.day)?;
+        let input = fs::read_to_string(&self.path).with_context(|| format!("couldn't read {}", self.path.display()))?;
+
+        let mut wrong = vec![];
+
+        for (part, expected) in &self.expected {
+            let answer = day.run(*part, &input, None)?;
+
+            if answer != *expected {
+                wrong.push(format!("part {part}: expected {expected}, got {answer}"));
+            }
+        }
+
+        Ok(wrong)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_sidecars() {
+        let expected = parse_answers("# only part 2 works on this one\n\n2 free\n").unwrap();
+
+        assert_eq!(expected, [(Part::Two, "free".to_owned())]);
+        assert_eq!(parse_answers("3 40").unwrap_err().to_string(), "line 1, column 1: expected part 1 or 2, found \"3\"");
+        assert!(parse_answers("1 ").is_err());
+    }
+
+    #[test]
+    fn examples_give_their_answers() {
+        // Example regions and machines have no business in the real caches
+        crate::memo::set_enabled(false);
+
+        let fixtures = discover(Path::new(EXAMPLES_DIR)).unwrap();
+
+        assert!(fixtures.iter().any(|fixture| fixture.day == 1));
+
+        let failures: Vec<_> = fixtures.iter().flat_map(|fixture| {
+            let wrong = fixture.check().unwrap_or_else(|err| vec![format!("{err:#}")]);
+
+            wrong.into_iter().map(move |wrong| format!("{}: {wrong}", fixture.path.display()))
+        }).collect();
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
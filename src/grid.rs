@@ -0,0 +1,217 @@
+use std::{fmt::Display, ops::{Index, IndexMut}};
+
+use crate::parse::{self, Cursor, ParseError};
+
+/// A rectangle of cells stored row by row, indexed with `(x, y)` from the top left
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const ALL_AROUND: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)];
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).map(|(x, y)| f(x, y)).collect();
+
+        Self { width, height, cells }
+    }
+
+    pub fn new(width: usize, height: usize, fill: T) -> Self where T: Clone {
+        Self { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Every char of every line becomes a cell through `map`, complaining about the ones it doesn't know
+    pub fn parse(s: &str, map: &[(char, T)]) -> Result<Self, ParseError> where T: Clone {
+        Self::from_lines(parse::lines(s), map)
+    }
+
+    /// Like [`Grid::parse`] for grids embedded in a bigger input; takes every line it's given
+    pub fn from_lines<'a>(lines: impl Iterator<Item = Cursor<'a>>, map: &[(char, T)]) -> Result<Self, ParseError> where T: Clone {
+        let options: String = map.iter().map(|(c, _)| c).collect();
+        let expected = match map {
+            [] => "nothing".to_owned(),
+            [(only, _)] => only.to_string(),
+            [init @ .., (last, _)] => {
+                let init: Vec<_> = init.iter().map(|(c, _)| c.to_string()).collect();
+
+                format!("one of {} or {last}", init.join(", "))
+            },
+        };
+
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for mut line in lines {
+            let start = cells.len();
+
+            while !line.is_empty() {
+                let c = line.char_of(&options, &expected)?;
+
+                cells.push(map.iter().find(|(k, _)| *k == c).unwrap().1.clone());
+            }
+
+            let row_width = cells.len() - start;
+
+            match width {
+                Some(width) if width != row_width => {
+                    return Err(line.error(format_args!("a row as wide as the first one ({width})")));
+                },
+                _ => width = Some(row_width),
+            }
+
+            height += 1;
+        }
+
+        Ok(Self { width: width.unwrap_or(0), height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks_exact doesn't like 0-wide rows
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Every cell with its position, row by row
+    pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells.iter().enumerate().map(|(i, cell)| ((i % self.width, i / self.width), cell))
+    }
+
+    /// The positions up, right, down and left of `(x, y)` that are still in the grid
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.offsets(x, y, &ORTHOGONAL)
+    }
+
+    /// Like [`Grid::neighbours4`], diagonals included
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.offsets(x, y, &ALL_AROUND)
+    }
+
+    fn offsets(&self, x: usize, y: usize, deltas: &'static [(isize, isize)]) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (width, height) = (self.width, self.height);
+
+        deltas.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx)?;
+            let y = y.checked_add_signed(dy)?;
+
+            (x < width && y < height).then_some((x, y))
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// A quarter turn clockwise
+    pub fn rotated_90deg(&self) -> Self where T: Clone {
+        Grid::from_fn(self.height, self.width, |x, y| self[(y, self.height - 1 - x)].clone())
+    }
+
+    /// Mirrored left to right
+    pub fn flipped(&self) -> Self where T: Clone {
+        Grid::from_fn(self.width, self.height, |x, y| self[(self.width - 1 - x, y)].clone())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).unwrap_or_else(|| panic!("({x}, {y}) is outside of a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(x, y).unwrap_or_else(|| panic!("({x}, {y}) is outside of a {width}x{height} grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: [(char, bool); 2] = [('#', true), ('.', false)];
+
+    fn chars(grid: &Grid<bool>) -> String {
+        grid.map(|&b| if b { '#' } else { '.' }).to_string()
+    }
+
+    #[test]
+    fn parse_and_index() {
+        let grid = Grid::parse("##.\n.#.", &MAP).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(grid[(1, 1)] && !grid[(0, 1)]);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.row(0), [true, true, false]);
+        assert_eq!(grid.cells().filter(|(_, c)| **c).map(|(p, _)| p).collect::<Vec<_>>(), [(0, 0), (1, 0), (1, 1)]);
+        assert_eq!(chars(&grid), "##.\n.#.\n");
+
+        assert_eq!(Grid::parse("#x", &MAP).unwrap_err().to_string(), "line 1, column 2: expected one of # or ., found \"x\"");
+        assert_eq!(Grid::parse("##\n#", &MAP).unwrap_err().to_string(), "line 2, column 2: expected a row as wide as the first one (2), found the end of the line");
+        assert!(Grid::parse("", &MAP).unwrap().is_empty());
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours4(1, 1).count(), 4);
+        assert_eq!(grid.neighbours8(1, 1).count(), 8);
+        assert_eq!(grid.neighbours8(2, 2).collect::<Vec<_>>(), [(1, 1), (2, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn rotate_and_flip() {
+        let grid = Grid::parse("##.\n.#.", &MAP).unwrap();
+
+        assert_eq!(chars(&grid.rotated_90deg()), ".#\n##\n..\n");
+        assert_eq!(chars(&grid.flipped()), ".##\n.#.\n");
+        assert_eq!(grid.rotated_90deg().rotated_90deg().rotated_90deg().rotated_90deg(), grid);
+    }
+}
@@ -0,0 +1,45 @@
+use std::{io::Read as _, path::{Path, PathBuf}};
+
+use anyhow::Context as _;
+
+/// Where the checked-in puzzle inputs live
+pub const INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/inputs");
+
+pub fn default_path(day: u8) -> PathBuf {
+    Path::new(INPUTS_DIR).join(format!("day{day}.txt"))
+}
+
+/// Reads the input for a day: `None` is the checked-in one, `-` is stdin, anything else is a path
+pub fn load(day: u8, input: Option<&Path>) -> anyhow::Result<String> {
+    let path = match input {
+        Some(path) if path == Path::new("-") => {
+            let mut buf = String::new();
+
+            std::io::stdin().read_to_string(&mut buf).context("couldn't read the input from stdin")?;
+
+            return Ok(buf);
+        }
+        Some(path) => path.to_path_buf(),
+        None => default_path(day),
+    };
+
+    std::fs::read_to_string(&path)
+        .with_context(|| format!("couldn't read the input for day {day} from {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_in_inputs() {
+        assert!(load(1, None).unwrap().starts_with(['L', 'R']));
+    }
+
+    #[test]
+    fn missing_file() {
+        let err = load(1, Some(Path::new("there/is/no/such/input.txt"))).unwrap_err();
+
+        assert!(format!("{err:#}").contains("there/is/no/such/input.txt"));
+    }
+}
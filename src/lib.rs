@@ -0,0 +1,31 @@
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+
+pub mod allocations;
+pub mod answers;
+pub mod bench;
+pub mod capture;
+pub mod cli;
+pub mod deadline;
+pub mod fixtures;
+pub mod grid;
+pub mod input;
+pub mod logger;
+pub mod memo;
+pub mod parse;
+pub mod progress;
+pub mod recorder;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod workers;
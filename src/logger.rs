@@ -0,0 +1,114 @@
+use std::{cell::RefCell, sync::atomic::{AtomicUsize, Ordering}};
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+use crate::{capture::{Capture, Slot}, progress};
+
+/// Diagnostics go to stderr, prefixed with their level; warnings are also kept around
+/// while [`capture_warnings`] is running so they can end up in the answer records
+struct Logger;
+
+static LOGGER: Logger = Logger;
+static LEVEL: AtomicUsize = AtomicUsize::new(LevelFilter::Info as usize);
+
+thread_local! {
+    /// Where the warnings logged on this thread are kept, if anywhere
+    static CAPTURE: Slot<String> = const { RefCell::new(Capture::none()) };
+}
+
+/// A capture of warnings, which threads working for the one running [`capture_warnings`] can join in on
+/// with [`Warnings::within`]
+#[derive(Debug, Clone, Default)]
+pub struct Warnings(Capture<String>);
+
+/// Sets up the logger, `AOC_LOG` (off, error, warn, info, debug, trace) picks the starting level
+/// and every step of `verbosity` makes it one level chattier (or quieter, if negative)
+pub fn init(verbosity: i8) {
+    let base = std::env::var("AOC_LOG").ok()
+        .and_then(|level| level.parse::<LevelFilter>().ok())
+        .unwrap_or(LevelFilter::Info);
+
+    let level = (base as i8 + verbosity).clamp(LevelFilter::Off as i8, LevelFilter::Trace as i8);
+
+    LEVEL.store(level as usize, Ordering::Relaxed);
+
+    // Another logger being there already (tests) is fine
+    let _ = log::set_logger(&LOGGER);
+
+    // Warnings get through no matter what, they're wanted for the records
+    log::set_max_level(level_filter(level as usize).max(LevelFilter::Warn));
+}
+
+fn level_filter(n: usize) -> LevelFilter {
+    LevelFilter::iter().nth(n).unwrap_or(LevelFilter::Trace)
+}
+
+/// Runs `f`, returning whatever warnings it logged in the meantime, on this thread or within [`Warnings::here`]
+/// on others
+pub fn capture_warnings<T>(f: impl FnOnce() -> T) -> (T, Vec<String>) {
+    let warnings = Capture::new();
+    let result = Warnings(warnings.clone()).within(f);
+
+    (result, warnings.take())
+}
+
+impl Warnings {
+    /// The capture running on this thread, or none
+    pub fn here() -> Self {
+        Self(Capture::current(&CAPTURE))
+    }
+
+    /// Runs `f` with the warnings logged on this thread going to this capture
+    pub fn within<T>(&self, f: impl FnOnce() -> T) -> T {
+        self.0.within(&CAPTURE, f)
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= level_filter(LEVEL.load(Ordering::Relaxed)) || metadata.level() <= Level::Warn
+    }
+
+    fn log(&self, record: &Record) {
+        if record.level() <= Level::Warn {
+            // Not there any more while a thread is being torn down
+            let _ = CAPTURE.try_with(|capture| capture.borrow().push(record.args().to_string()));
+        }
+
+        if record.level() <= level_filter(LEVEL.load(Ordering::Relaxed)) {
+            progress::clear_bar();
+            eprintln!("[{}] {}", record.level().as_str().to_lowercase(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warnings_are_captured() {
+        init(-2);
+
+        let (answer, warnings) = capture_warnings(|| {
+            log::info!("not this one");
+            log::warn!("region {} doesn't fit", 3);
+
+            let warnings = Warnings::here();
+
+            std::thread::spawn(move || warnings.within(|| log::error!("nor does {}", 4))).join().unwrap();
+            std::thread::spawn(|| log::warn!("someone else's")).join().unwrap();
+
+            42
+        });
+
+        assert_eq!(answer, 42);
+        assert_eq!(warnings, ["region 3 doesn't fit", "nor does 4"]);
+
+        log::warn!("after the fact");
+
+        assert!(capture_warnings(|| ()).1.is_empty());
+    }
+}
@@ -0,0 +1,225 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{File, OpenOptions},
+    io::{Read as _, Write as _},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Mutex, atomic::{AtomicBool, Ordering}},
+};
+
+/// Where results are kept between runs, unless `AOC_CACHE` points somewhere else
+pub const CACHE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/cache");
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Results of a slow per-item solver that outlive the process
+///
+/// Items are keyed by a hash of whatever text describes them (the input line, the region and its shapes...),
+/// and each solver version gets its own file, so bumping the version starts over. Records are appended one
+/// line per write, which lets several processes share a file, and a line cut short by a crash is dropped
+/// the next time it's opened.
+pub struct Memo<V> {
+    known: Mutex<HashMap<u64, V>>,
+    file: Option<Mutex<File>>,
+}
+
+/// Turns every memo into a passthrough, e.g. so that benchmarks time the solvers and not the disk
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// FNV-1a, picked because it'll hash the same tomorrow, unlike std's hasher
+pub fn content_hash(item: &str) -> u64 {
+    item.bytes().fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
+
+pub fn path(dir: &Path, solver: &str, version: u32) -> PathBuf {
+    dir.join(format!("{solver}.v{version}.txt"))
+}
+
+impl<V: Clone + Display + FromStr> Memo<V> {
+    /// The memo for a solver in the cache directory; if that can't be opened, results are only kept in memory
+    pub fn open(solver: &str, version: u32) -> Self {
+        if !ENABLED.load(Ordering::Relaxed) {
+            return Self::in_memory();
+        }
+
+        let dir = std::env::var_os("AOC_CACHE").map_or_else(|| PathBuf::from(CACHE_DIR), PathBuf::from);
+
+        Self::at(&dir, solver, version).unwrap_or_else(|err| {
+            log::warn!("couldn't open the {solver} cache in {}, nothing will be saved: {err}", dir.display());
+
+            Self::in_memory()
+        })
+    }
+
+    pub fn at(dir: &Path, solver: &str, version: u32) -> std::io::Result<Self> {
+        std::fs::create_dir_all(dir)?;
+
+        let mut file = OpenOptions::new().read(true).append(true).create(true).open(path(dir, solver, version))?;
+        let mut contents = String::new();
+
+        file.read_to_string(&mut contents)?;
+
+        // Only a newline says a record got written in full, `1f2e 12` could be what's left of `1f2e 123`
+        let end = contents.rfind('\n').map_or(0, |newline| newline + 1);
+        let (whole, torn) = contents.split_at(end);
+        let mut known = HashMap::new();
+
+        for line in whole.lines() {
+            let record = line.split_once(' ').and_then(|(hash, value)| {
+                Some((u64::from_str_radix(hash, 16).ok()?, value.parse().ok()?))
+            });
+
+            match record {
+                Some((hash, value)) => { known.insert(hash, value); },
+                None => log::debug!("skipping a broken {solver} cache record: {line:?}"),
+            }
+        }
+
+        // Cut off so the next record starts on a line of its own; at worst that loses a record another
+        // process appended just now, which only means solving it again
+        if !torn.is_empty() {
+            log::debug!("dropping a {solver} cache record cut off mid-line: {torn:?}");
+
+            file.set_len(end as u64)?;
+        }
+
+        Ok(Self { known: Mutex::new(known), file: Some(Mutex::new(file)) })
+    }
+
+    pub fn in_memory() -> Self {
+        Self { known: Mutex::new(HashMap::new()), file: None }
+    }
+
+    pub fn len(&self) -> usize {
+        self.known.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, item: &str) -> Option<V> {
+        self.known.lock().unwrap().get(&content_hash(item)).cloned()
+    }
+
+    pub fn insert(&self, item: &str, value: V) {
+        let hash = content_hash(item);
+        let record = format!("{hash:016x} {value}\n");
+
+        assert_eq!(record.lines().count(), 1, "memo values have to fit on one line");
+
+        if let Some(file) = &self.file {
+            // One write per record, appends don't interleave with other processes
+            if let Err(err) = file.lock().unwrap().write_all(record.as_bytes()) {
+                log::warn!("couldn't save a result to the cache: {err}");
+            }
+        }
+
+        self.known.lock().unwrap().insert(hash, value);
+    }
+
+    /// The remembered value, or `solve`'s, which is remembered from then on
+    pub fn get_or_insert_with(&self, item: &str, solve: impl FnOnce() -> V) -> V {
+        if let Some(value) = self.get(item) {
+            return value;
+        }
+
+        let value = solve();
+
+        self.insert(item, value.clone());
+
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-memo-{name}-{}", std::process::id()));
+
+        let _ = std::fs::remove_dir_all(&dir);
+
+        dir
+    }
+
+    #[test]
+    fn survives_reopening_and_torn_records() {
+        let dir = scratch_dir("reopen");
+
+        let memo = Memo::<u64>::at(&dir, "solver", 1).unwrap();
+        assert_eq!(memo.get_or_insert_with("machine a", || 3), 3);
+        assert_eq!(memo.get_or_insert_with("machine a", || unreachable!()), 3);
+        memo.insert("machine b", 5);
+        drop(memo);
+
+        // A crash halfway through a record
+        OpenOptions::new().append(true).open(path(&dir, "solver", 1)).unwrap().write_all(b"00ab").unwrap();
+
+        let memo = Memo::<u64>::at(&dir, "solver", 1).unwrap();
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.get("machine b"), Some(5));
+        memo.insert("machine c", 8);
+        drop(memo);
+
+        let memo = Memo::<u64>::at(&dir, "solver", 1).unwrap();
+        assert_eq!(memo.get("machine c"), Some(8));
+        assert_eq!(memo.len(), 3);
+
+        // A new version doesn't trust the old results
+        assert!(Memo::<u64>::at(&dir, "solver", 2).unwrap().is_empty());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn truncated_records_are_not_trusted() {
+        let dir = scratch_dir("truncated");
+
+        let memo = Memo::<u64>::at(&dir, "solver", 1).unwrap();
+        memo.insert("machine a", 3);
+        drop(memo);
+
+        // `machine b` was going to be 123
+        let torn = format!("{:016x} 12", content_hash("machine b"));
+        OpenOptions::new().append(true).open(path(&dir, "solver", 1)).unwrap().write_all(torn.as_bytes()).unwrap();
+
+        for _ in 0..2 {
+            let memo = Memo::<u64>::at(&dir, "solver", 1).unwrap();
+
+            assert_eq!(memo.get("machine b"), None);
+            assert_eq!(memo.get("machine a"), Some(3));
+        }
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn concurrent_writers() {
+        let dir = scratch_dir("concurrent");
+
+        std::thread::scope(|scope| {
+            for writer in 0..4 {
+                let dir = &dir;
+
+                scope.spawn(move || {
+                    let memo = Memo::<bool>::at(dir, "regions", 1).unwrap();
+
+                    for region in 0..100 {
+                        memo.insert(&format!("{writer} {region}"), region % 2 == 0);
+                    }
+                });
+            }
+        });
+
+        let memo = Memo::<bool>::at(&dir, "regions", 1).unwrap();
+        assert_eq!(memo.len(), 400);
+        assert_eq!(memo.get("3 42"), Some(true));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
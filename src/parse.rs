@@ -0,0 +1,193 @@
+use std::{fmt::Display, str::FromStr};
+
+/// A malformed input, pointing at where things went wrong
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based
+    pub line: usize,
+    /// 1-based, in characters
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: expected {}, found ", self.line, self.column, self.expected)?;
+
+        if self.found.is_empty() {
+            f.write_str("the end of the line")
+        } else {
+            write!(f, "{:?}", self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Walks a single line of input left to right, keeping track of where it is for errors
+#[derive(Debug, Clone)]
+pub struct Cursor<'a> {
+    line: usize,
+    text: &'a str,
+    offset: usize,
+}
+
+/// Every line of the input as a cursor, numbered from 1
+pub fn lines(s: &str) -> impl Iterator<Item = Cursor<'_>> {
+    s.lines().enumerate().map(|(index, text)| Cursor::new(index + 1, text))
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(line: usize, text: &'a str) -> Self {
+        Self { line, text, offset: 0 }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// 1-based column of the next character
+    pub fn column(&self) -> usize {
+        self.text[..self.offset].chars().count() + 1
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.offset..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// An error at the current position, quoting whatever comes next
+    pub fn error(&self, expected: impl Display) -> ParseError {
+        let rest = self.rest();
+        let found = match rest.find(|c: char| c.is_whitespace() || c.is_ascii_punctuation()) {
+            Some(0) => &rest[..rest.chars().next().map_or(0, char::len_utf8)],
+            Some(end) => &rest[..end],
+            None => rest,
+        };
+
+        ParseError {
+            line: self.line,
+            column: self.column(),
+            expected: expected.to_string(),
+            found: found.to_owned(),
+        }
+    }
+
+    pub fn next_char(&mut self) -> Option<char> {
+        let c = self.peek()?;
+
+        self.offset += c.len_utf8();
+
+        Some(c)
+    }
+
+    /// Consumes the next character if it's in `options`
+    pub fn char_of(&mut self, options: &str, expected: impl Display) -> Result<char, ParseError> {
+        match self.peek() {
+            Some(c) if options.contains(c) => {
+                self.offset += c.len_utf8();
+
+                Ok(c)
+            },
+            _ => Err(self.error(expected)),
+        }
+    }
+
+    pub fn expect(&mut self, token: &str) -> Result<(), ParseError> {
+        if self.rest().starts_with(token) {
+            self.offset += token.len();
+
+            Ok(())
+        } else {
+            Err(self.error(format_args!("{token:?}")))
+        }
+    }
+
+    /// Consumes `token` if it's next, without complaining if it isn't
+    pub fn eat(&mut self, token: &str) -> bool {
+        self.expect(token).is_ok()
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+
+        self.offset += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consumes a run of digits (with a leading minus if `T` might be negative) and parses it
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let rest = self.rest();
+        let sign = usize::from(rest.starts_with('-'));
+        let digits = rest[sign..].len() - rest[sign..].trim_start_matches(|c: char| c.is_ascii_digit()).len();
+
+        match rest[..sign + digits].parse() {
+            Ok(n) if digits > 0 => {
+                self.offset += sign + digits;
+
+                Ok(n)
+            },
+            _ => Err(self.error("a number")),
+        }
+    }
+
+    /// Consumes everything up to (not including) `delimiter`, or the rest of the line
+    pub fn take_until(&mut self, delimiter: char) -> &'a str {
+        self.take_while(|c| c != delimiter)
+    }
+
+    pub fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let end = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+
+        self.offset += end;
+
+        &rest[..end]
+    }
+
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("the end of the line"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_and_tokens() {
+        let mut cursor = Cursor::new(1, "12x-5: ok");
+
+        assert_eq!(cursor.number::<u32>(), Ok(12));
+        assert!(cursor.eat("x"));
+        assert_eq!(cursor.number::<i32>(), Ok(-5));
+        assert_eq!(cursor.expect(": "), Ok(()));
+        assert_eq!(cursor.take_until(' '), "ok");
+        assert_eq!(cursor.end(), Ok(()));
+    }
+
+    #[test]
+    fn errors_point_at_the_problem() {
+        let mut cursor = Cursor::new(3, "R4x");
+
+        assert_eq!(cursor.char_of("LR", "a direction"), Ok('R'));
+        assert_eq!(cursor.number::<u32>(), Ok(4));
+
+        let err = cursor.end().unwrap_err();
+
+        assert_eq!((err.line, err.column, err.found.as_str()), (3, 3, "x"));
+        assert_eq!(err.to_string(), "line 3, column 3: expected the end of the line, found \"x\"");
+        assert_eq!(Cursor::new(1, "").number::<u8>().unwrap_err().to_string(), "line 1, column 1: expected a number, found the end of the line");
+    }
+}
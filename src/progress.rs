@@ -0,0 +1,181 @@
+use std::{
+    fmt::Display,
+    io::IsTerminal as _,
+    sync::{Mutex, atomic::{AtomicBool, AtomicUsize, Ordering}},
+    time::{Duration, Instant},
+};
+
+/// Runs shorter than this don't get a bar at all, it'd only flicker
+const FIRST_DRAW: Duration = Duration::from_millis(250);
+const REDRAW: Duration = Duration::from_millis(100);
+/// How often there's a line about it when stderr isn't a terminal
+const LOG_EVERY: Duration = Duration::from_secs(5);
+const BAR_WIDTH: usize = 30;
+
+/// Whether a bar is sitting on the last line of stderr, which log lines have to clear first
+static BAR_SHOWN: AtomicBool = AtomicBool::new(false);
+
+/// How far along a long batch of items is, for the solvers that take a while
+///
+/// On a terminal it's a bar redrawn as items get done, otherwise an info line every few seconds. Either way
+/// it stays quiet for runs that finish quickly, and says how it went when dropped if it said anything before.
+/// It can be ticked from any thread.
+pub struct Progress {
+    what: String,
+    total: usize,
+    done: AtomicUsize,
+    best: Mutex<Option<String>>,
+    started: Instant,
+    last_shown: Mutex<Option<Instant>>,
+    live: bool,
+}
+
+/// Takes the bar off the screen so a log line doesn't get glued to it, the next tick draws it again
+pub fn clear_bar() {
+    if BAR_SHOWN.swap(false, Ordering::SeqCst) {
+        eprint!("\r\x1b[2K");
+    }
+}
+
+impl Progress {
+    /// Progress through `total` items, which `what` names ("regions", "machines"...)
+    pub fn new(what: impl Into<String>, total: usize) -> Self {
+        Self {
+            what: what.into(),
+            total,
+            done: AtomicUsize::new(0),
+            best: Mutex::new(None),
+            started: Instant::now(),
+            last_shown: Mutex::new(None),
+            live: std::io::stderr().is_terminal(),
+        }
+    }
+
+    pub fn tick(&self) {
+        self.advance(1);
+    }
+
+    pub fn advance(&self, items: usize) {
+        self.done.fetch_add(items, Ordering::Relaxed);
+        self.show();
+    }
+
+    /// The best value found so far, whatever that means for the search
+    pub fn set_best(&self, best: impl Display) {
+        *self.best.lock().unwrap() = Some(best.to_string());
+    }
+
+    fn show(&self) {
+        if !log::log_enabled!(log::Level::Info) {
+            return;
+        }
+
+        // Someone else is already at it
+        let Ok(mut last_shown) = self.last_shown.try_lock() else {
+            return;
+        };
+
+        let now = Instant::now();
+        let (since, wait) = match *last_shown {
+            None => (self.started, if self.live { FIRST_DRAW } else { LOG_EVERY }),
+            Some(at) => (at, if self.live { REDRAW } else { LOG_EVERY }),
+        };
+
+        if now - since < wait {
+            return;
+        }
+
+        *last_shown = Some(now);
+
+        let status = self.status(now - self.started);
+
+        if self.live {
+            let filled = BAR_WIDTH * self.done.load(Ordering::Relaxed).min(self.total) / self.total.max(1);
+
+            eprint!("\r\x1b[2K[{}{}] {status}", "#".repeat(filled), ".".repeat(BAR_WIDTH - filled));
+            BAR_SHOWN.store(true, Ordering::SeqCst);
+        } else {
+            log::info!("{status}");
+        }
+    }
+
+    /// Like "250/1000 regions (25%), 25.0/s, eta 30s, best 599"
+    fn status(&self, elapsed: Duration) -> String {
+        let done = self.done.load(Ordering::Relaxed);
+        let mut status = format!("{done}/{} {} ({}%)", self.total, self.what, 100 * done / self.total.max(1));
+
+        let rate = done as f64 / elapsed.as_secs_f64();
+
+        if rate.is_finite() && rate > 0.0 {
+            status += &format!(", {rate:.1}/s");
+
+            if done < self.total {
+                status += &format!(", eta {}", short(Duration::from_secs_f64((self.total - done) as f64 / rate)));
+            }
+        }
+
+        if let Some(best) = &*self.best.lock().unwrap() {
+            status += &format!(", best {best}");
+        }
+
+        status
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if self.last_shown.get_mut().unwrap().is_none() {
+            return;
+        }
+
+        clear_bar();
+
+        let done = *self.done.get_mut();
+        let mut summary = format!("{done}/{} {} in {}", self.total, self.what, short(self.started.elapsed()));
+
+        if let Some(best) = self.best.get_mut().unwrap() {
+            summary += &format!(", best {best}");
+        }
+
+        log::info!("{summary}");
+    }
+}
+
+/// Down to the second, which is all an ETA is good for
+fn short(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+
+    match seconds {
+        0..60 => format!("{seconds}s"),
+        60..3600 => format!("{}m{:02}s", seconds / 60, seconds % 60),
+        _ => format!("{}h{:02}m", seconds / 3600, seconds / 60 % 60),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_lines() {
+        let progress = Progress::new("regions", 1000);
+
+        assert_eq!(progress.status(Duration::ZERO), "0/1000 regions (0%)");
+
+        progress.advance(250);
+
+        assert_eq!(progress.status(Duration::from_secs(10)), "250/1000 regions (25%), 25.0/s, eta 30s");
+
+        progress.set_best(599);
+        progress.advance(750);
+
+        assert_eq!(progress.status(Duration::from_secs(40)), "1000/1000 regions (100%), 25.0/s, best 599");
+    }
+
+    #[test]
+    fn short_durations() {
+        assert_eq!(short(Duration::from_millis(12_900)), "12s");
+        assert_eq!(short(Duration::from_secs(75)), "1m15s");
+        assert_eq!(short(Duration::from_secs(3 * 3600 + 5 * 60 + 9)), "3h05m");
+    }
+}
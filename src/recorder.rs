@@ -0,0 +1,220 @@
+use std::{borrow::Cow, collections::HashMap, fs::{self, File}, io::{BufWriter, Write as _}, path::{Path, PathBuf}};
+
+use anyhow::{Context as _, bail};
+
+use crate::grid::Grid;
+
+pub type Rgb = [u8; 3];
+
+/// The frames of a simulation, one grid of colours per step, for looking at what it did instead of just the count
+#[derive(Debug, Default)]
+pub struct Recorder {
+    frames: Vec<Grid<Rgb>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum ImageFormat {
+    /// One animated file
+    #[default]
+    Gif,
+    /// A directory of numbered frames
+    Png,
+    /// The same, in the format anything can read
+    Ppm,
+}
+
+/// How the frames come out
+#[derive(Debug, Clone, Copy)]
+pub struct Export {
+    pub format: ImageFormat,
+    /// Pixels per cell, each way
+    pub scale: usize,
+    /// Hundredths of a second per frame, for the GIF
+    pub delay: u16,
+}
+
+impl Recorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, frame: Grid<Rgb>) {
+        if let Some(first) = self.frames.first() {
+            assert_eq!((frame.width(), frame.height()), (first.width(), first.height()), "frames have to be the same size");
+        }
+
+        self.frames.push(frame);
+    }
+
+    pub fn frames(&self) -> &[Grid<Rgb>] {
+        &self.frames
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Writes the GIF to `out`, or the frames into the directory `out` as 0000.png, 0001.png...,
+    /// returning what was written
+    pub fn export(&self, out: &Path, export: Export) -> anyhow::Result<Vec<PathBuf>> {
+        let Some(first) = self.frames.first() else {
+            bail!("there are no frames to write");
+        };
+
+        let scale = export.scale.max(1);
+        let (width, height) = (first.width() * scale, first.height() * scale);
+
+        if export.format == ImageFormat::Gif {
+            self.write_gif(out, width, height, scale, export.delay)
+                .with_context(|| format!("couldn't write {}", out.display()))?;
+
+            return Ok(vec![out.to_path_buf()]);
+        }
+
+        fs::create_dir_all(out).with_context(|| format!("couldn't make {}", out.display()))?;
+
+        self.frames.iter().enumerate().map(|(index, frame)| {
+            let extension = if export.format == ImageFormat::Png { "png" } else { "ppm" };
+            let path = out.join(format!("{index:04}.{extension}"));
+            let pixels: Vec<u8> = scaled(frame, scale).flatten().collect();
+            let mut file = BufWriter::new(File::create(&path)?);
+
+            if export.format == ImageFormat::Png {
+                let mut encoder = png::Encoder::new(file, width as u32, height as u32);
+
+                encoder.set_color(png::ColorType::Rgb);
+                encoder.set_depth(png::BitDepth::Eight);
+                encoder.write_header()?.write_image_data(&pixels)?;
+            } else {
+                write!(file, "P6\n{width} {height}\n255\n")?;
+                file.write_all(&pixels)?;
+                file.flush()?;
+            }
+
+            Ok(path)
+        }).collect::<anyhow::Result<_>>().with_context(|| format!("couldn't write the frames to {}", out.display()))
+    }
+
+    fn write_gif(&self, out: &Path, width: usize, height: usize, scale: usize, delay: u16) -> anyhow::Result<()> {
+        let (Ok(width), Ok(height)) = (u16::try_from(width), u16::try_from(height)) else {
+            bail!("{width}x{height} is too big for a GIF, try a smaller --scale");
+        };
+
+        // The simulations only ever use a handful of colours, so they all fit in one palette and nothing
+        // has to be dithered
+        let mut palette = HashMap::new();
+
+        for frame in &self.frames {
+            for (_, colour) in frame.cells() {
+                let next = palette.len();
+
+                palette.entry(*colour).or_insert(next);
+            }
+        }
+
+        if palette.len() > 256 {
+            bail!("{} colours don't fit in a GIF's 256", palette.len());
+        }
+
+        let mut colours = vec![[0; 3]; palette.len()];
+
+        for (&colour, &index) in &palette {
+            colours[index] = colour;
+        }
+
+        let mut encoder = gif::Encoder::new(BufWriter::new(File::create(out)?), width, height, colours.as_flattened())?;
+
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+
+        for frame in &self.frames {
+            let indices = scaled(frame, scale).map(|colour| palette[&colour] as u8).collect();
+
+            encoder.write_frame(&gif::Frame { width, height, delay, buffer: Cow::Owned(indices), ..gif::Frame::default() })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The frame's pixels row by row, every cell blown up to a `scale` by `scale` square
+fn scaled(frame: &Grid<Rgb>, scale: usize) -> impl Iterator<Item = Rgb> {
+    frame.rows().flat_map(move |row| {
+        (0..scale).flat_map(move |_| row.iter().flat_map(move |&colour| std::iter::repeat_n(colour, scale)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgb = [255, 0, 0];
+    const BLUE: Rgb = [0, 0, 255];
+
+    fn recording() -> Recorder {
+        let mut recorder = Recorder::new();
+
+        recorder.push(Grid::from_fn(3, 2, |x, _| if x == 0 { RED } else { BLUE }));
+        recorder.push(Grid::new(3, 2, BLUE));
+
+        recorder
+    }
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-recorder-{name}-{}", std::process::id()));
+
+        let _ = fs::remove_dir_all(&dir);
+
+        dir
+    }
+
+    #[test]
+    fn frames_as_images() {
+        let dir = scratch_dir("frames");
+        let recorder = recording();
+
+        let ppms = recorder.export(&dir, Export { format: ImageFormat::Ppm, scale: 2, delay: 10 }).unwrap();
+        let ppm = fs::read(&ppms[0]).unwrap();
+
+        assert_eq!(ppms.len(), 2);
+        assert!(ppm.starts_with(b"P6\n6 4\n255\n"));
+        // The first row is red, red, blue, blue... and then again
+        assert_eq!(&ppm[11..17], [RED, RED].as_flattened());
+        assert_eq!(&ppm[11 + 18..11 + 24], [RED, RED].as_flattened());
+        assert_eq!(ppm.len(), 11 + 6 * 4 * 3);
+
+        let pngs = recorder.export(&dir, Export { format: ImageFormat::Png, scale: 1, delay: 10 }).unwrap();
+        let mut reader = png::Decoder::new(std::io::BufReader::new(File::open(&pngs[1]).unwrap())).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size().unwrap()];
+        reader.next_frame(&mut pixels).unwrap();
+
+        assert_eq!(pixels, [BLUE; 6].as_flattened());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn animated_gif() {
+        let dir = scratch_dir("gif");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("waves.gif");
+
+        recording().export(&path, Export { format: ImageFormat::Gif, scale: 3, delay: 25 }).unwrap();
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(File::open(&path).unwrap()).unwrap();
+
+        let first = decoder.read_next_frame().unwrap().unwrap();
+        assert_eq!((first.width, first.height, first.delay), (9, 6, 25));
+        assert_eq!(&first.buffer[..4], [255, 0, 0, 255]);
+
+        assert!(decoder.read_next_frame().unwrap().is_some());
+        assert!(decoder.read_next_frame().unwrap().is_none());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,478 @@
+use std::{fmt::Display, fs, path::Path, str::FromStr, time::{Duration, Instant}};
+
+use anyhow::{Context as _, bail};
+use rand::{SeedableRng as _, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+
+use crate::{allocations, answers::Answers, bench::{self, Measurement}, deadline, input, logger, memo, recorder::{Export, Recorder}, solution::{self, CrossCheck, Solution}, day1, day2, day3, day4, day5, day6, day7, day8, day9, day10, day11, day12};
+
+pub struct Day {
+    pub day: u8,
+    pub name: &'static str,
+    /// What the variants give one answer for
+    pub item: &'static str,
+    pub solve: fn(Part, &str, Option<&str>) -> anyhow::Result<String>,
+    pub variants: fn(Part) -> Vec<&'static str>,
+    pub cross_check: fn(Part, &str, &[String]) -> anyhow::Result<CrossCheck>,
+    pub bench: bench::Measure,
+    pub check: fn(&str) -> anyhow::Result<()>,
+    pub generate: fn(&mut StdRng, usize) -> String,
+    /// The biggest size `generate` can do
+    pub max_size: usize,
+    pub record: fn(&str, &mut Recorder) -> anyhow::Result<()>,
+    pub trace: fn(Part, &str) -> anyhow::Result<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "u8", try_from = "u8")]
+pub enum Part {
+    One,
+    Two
+}
+
+/// How answers and reports get printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    /// One JSON record per answer, one per line
+    Json,
+}
+
+/// An answer as `--format json` prints it
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub variant: String,
+    /// Parsing and solving, in nanoseconds
+    #[serde(with = "bench::nanos")]
+    pub elapsed: Duration,
+    /// Whatever the solver logged as a warning along the way
+    pub warnings: Vec<String>,
+    /// Items the solver gave up on because of `--timeout`, the answer leaves them out
+    pub unresolved: Vec<usize>,
+    /// How the answer came about, with `--trace`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub trace: Vec<String>,
+}
+
+/// Which days `aoc run` should go through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Selection {
+    All,
+    Day(u8)
+}
+
+pub static DAYS: &[Day] = &[
+    Day::new::<day1::Rotations>(1, "Unsafe"),
+    Day::new::<day2::Elfilter>(2, "Elfilter"),
+    Day::new::<day3::Lobby>(3, "Lobby"),
+    Day::new::<day4::Printing>(4, "Printing"),
+    Day::new::<day5::Cafeteria>(5, "Cafeteria"),
+    Day::new::<day6::Cephalopostulate>(6, "Cephalopostulate"),
+    Day::new::<day7::TheScientist>(7, "TheScientist"),
+    Day::new::<day8::WeebsLastTour>(8, "WeebsLastTour"),
+    Day::new::<day9::BijouTheater>(9, "BijouTheater"),
+    Day::new::<day10::Factory>(10, "Factory"),
+    Day::new::<day11::Hiroshima>(11, "Hiroshima"),
+    Day::new::<day12::BullshitPacking>(12, "BullshitPacking"),
+];
+
+pub fn find_day(day: u8) -> anyhow::Result<&'static Day> {
+    DAYS.iter().find(|d| d.day == day).with_context(|| {
+        let first = DAYS.first().map_or(0, |d| d.day);
+        let last = DAYS.last().map_or(0, |d| d.day);
+
+        format!("there's no day {day}, pick one between {first} and {last}")
+    })
+}
+
+impl Day {
+    pub const fn new<S: Solution>(day: u8, name: &'static str) -> Self {
+        Self {
+            day,
+            name,
+            item: S::ITEM,
+            solve: solution::answer::<S>,
+            variants: solution::variant_names::<S>,
+            cross_check: solution::cross_check::<S>,
+            bench: bench::measure::<S>,
+            check: solution::check::<S>,
+            generate: S::generate,
+            max_size: S::MAX_SIZE,
+            record: solution::record::<S>,
+            trace: solution::trace::<S>,
+        }
+    }
+
+    /// Answers a part with its own solver, or with one of its variants
+    pub fn run(&self, part: Part, input: &str, variant: Option<&str>) -> anyhow::Result<String> {
+        (self.solve)(part, input, variant).with_context(|| format!("day {}", self.day))
+    }
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => bail!("there's no part {s:?}, it's either 1 or 2")
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> u8 {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(n: u8) -> Result<Self, Self::Error> {
+        n.to_string().parse()
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+impl FromStr for Selection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Selection::All);
+        }
+
+        let day = s.parse().with_context(|| format!("expected a day number or \"all\", got {s:?}"))?;
+
+        find_day(day)?;
+
+        Ok(Selection::Day(day))
+    }
+}
+
+impl Selection {
+    pub fn days(self) -> impl Iterator<Item = &'static Day> {
+        DAYS.iter().filter(move |d| match self {
+            Selection::All => true,
+            Selection::Day(day) => d.day == day,
+        })
+    }
+}
+
+/// Runs the selected parts, or both when none is given
+///
+/// With `trace`, each answer comes with the steps that led to it, for the days that can tell.
+pub fn run(selection: Selection, part: Option<Part>, input: Option<&Path>, variant: Option<&str>, format: Format, trace: bool) -> anyhow::Result<()> {
+    if selection == Selection::All && (input.is_some() || variant.is_some()) {
+        bail!("--input and --variant only make sense for a single day");
+    }
+
+    for day in selection.days() {
+        let input = input::load(day.day, input)?;
+
+        for part in parts(part) {
+            let start = Instant::now();
+            let ((answer, warnings), unresolved) = deadline::capture_unresolved(|| {
+                logger::capture_warnings(|| day.run(part, &input, variant))
+            });
+            let elapsed = start.elapsed();
+            let answer = answer?;
+            let steps = if trace { (day.trace)(part, &input).with_context(|| format!("day {}", day.day))? } else { vec![] };
+
+            if trace && steps.is_empty() {
+                log::warn!("day {} part {part} has nothing to trace", day.day);
+            }
+
+            match format {
+                Format::Text => {
+                    for line in &steps {
+                        println!("day {} part {part} {line}", day.day);
+                    }
+
+                    print!("day {} part {part}: {answer}", day.day);
+
+                    if let Some(variant) = variant {
+                        print!(" ({variant})");
+                    }
+
+                    if !unresolved.is_empty() {
+                        print!(" with {} {}(s) unresolved: {}", unresolved.len(), day.item, comma_separated(&unresolved));
+                    }
+
+                    println!();
+                },
+                Format::Json => {
+                    let record = Record {
+                        day: day.day,
+                        part,
+                        answer,
+                        variant: variant.unwrap_or(solution::DEFAULT).to_owned(),
+                        elapsed,
+                        warnings,
+                        unresolved,
+                        trace: steps,
+                    };
+
+                    println!("{}", serde_json::to_string(&record)?);
+                },
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn comma_separated(indices: &[usize]) -> String {
+    indices.iter().map(usize::to_string).collect::<Vec<_>>().join(", ")
+}
+
+fn parts(part: Option<Part>) -> Vec<Part> {
+    match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    }
+}
+
+/// Runs every variant of the selected parts (or just the ones in `only`) and reports where they disagree
+pub fn cross_check(selection: Selection, part: Option<Part>, input: Option<&Path>, only: &[String]) -> anyhow::Result<()> {
+    if selection == Selection::All && (input.is_some() || !only.is_empty()) {
+        bail!("--input and --variant only make sense for a single day");
+    }
+
+    let mut disagreements = 0;
+
+    for day in selection.days() {
+        let input = input::load(day.day, input)?;
+
+        for part in parts(part) {
+            let check = (day.cross_check)(part, &input, only).with_context(|| format!("day {}", day.day))?;
+
+            match check.variants.as_slice() {
+                [] => println!("day {} part {part}: nothing to cross-check", day.day),
+                [only] => println!("day {} part {part}: only {only} to go by", day.day),
+                variants if check.disagreements.is_empty() => {
+                    println!("day {} part {part}: {} agree on all {} {}(s)", day.day, variants.join(", "), check.items - check.unresolved.len(), check.item);
+                },
+                variants => {
+                    println!("day {} part {part}: {} disagree on {} {}(s)", day.day, variants.join(" vs "), check.disagreements.len(), check.item);
+
+                    for disagreement in check.disagreements.iter() {
+                        println!("  {} {}: {disagreement}", check.item, disagreement.index);
+                    }
+
+                    disagreements += check.disagreements.len();
+                },
+            }
+
+            if !check.unresolved.is_empty() {
+                println!("  left out {} unresolved {}(s): {}", check.unresolved.len(), check.item, comma_separated(&check.unresolved));
+            }
+        }
+    }
+
+    if disagreements > 0 {
+        bail!("the variants disagree on {disagreements} item(s)");
+    }
+
+    Ok(())
+}
+
+/// Re-runs the selected days and compares every answer against src/answers.txt
+pub fn verify(selection: Selection) -> anyhow::Result<()> {
+    let mut mismatches = 0;
+
+    for day in selection.days() {
+        let input = input::load(day.day, None)?;
+
+        for check in Answers::checked_in().verify(day, &input)? {
+            println!("{check}");
+
+            if !check.is_ok() {
+                mismatches += 1;
+            }
+        }
+    }
+
+    if mismatches > 0 {
+        bail!("{mismatches} answer(s) don't match the record");
+    }
+
+    Ok(())
+}
+
+/// Where `aoc bench` should put its results and what to hold them up against
+pub struct BenchOptions<'a> {
+    pub runs: usize,
+    pub variants: &'a [String],
+    pub format: Format,
+    pub save: Option<&'a Path>,
+    pub baseline: Option<&'a Path>,
+    /// Percent slower than the baseline before it counts as a regression
+    pub tolerance: f64,
+    /// Count what each phase allocates as well
+    pub allocations: bool,
+}
+
+/// Times the selected parts and compares them against a saved baseline if there is one
+pub fn bench(selection: Selection, part: Option<Part>, input: Option<&Path>, options: BenchOptions) -> anyhow::Result<()> {
+    let only_all = options.variants.iter().all(|name| name == "all");
+
+    if selection == Selection::All && (input.is_some() || !only_all) {
+        bail!("--input and --variant only make sense for a single day");
+    }
+
+    let baseline: Vec<Measurement> = match options.baseline {
+        Some(path) => {
+            let json = fs::read_to_string(path).with_context(|| format!("couldn't read the baseline from {}", path.display()))?;
+
+            serde_json::from_str(&json).with_context(|| format!("{} isn't a baseline saved by aoc bench", path.display()))?
+        },
+        None => vec![],
+    };
+
+    // Timing how fast the cache is read back isn't the point
+    memo::set_enabled(false);
+    allocations::set_enabled(options.allocations)?;
+
+    let mut measurements = vec![];
+
+    for day in selection.days() {
+        let input = input::load(day.day, input)?;
+
+        for part in parts(part) {
+            let timed = (day.bench)(part, &input, options.variants, options.runs).with_context(|| format!("day {}", day.day))?;
+
+            measurements.extend(timed.into_iter().map(|m| Measurement { day: day.day, ..m }));
+        }
+    }
+
+    let comparisons = bench::compare(&measurements, &baseline, options.tolerance);
+
+    match options.format {
+        Format::Text => bench::print_table(&measurements, &comparisons),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&measurements)?),
+    }
+
+    if let Some(path) = options.save {
+        fs::write(path, serde_json::to_string_pretty(&measurements)?).with_context(|| format!("couldn't save the baseline to {}", path.display()))?;
+    }
+
+    let regressions: Vec<_> = comparisons.iter().filter(|c| c.regressed).collect();
+
+    if !regressions.is_empty() {
+        let names: Vec<_> = regressions.iter().map(|c| format!("day {} part {} {}", c.day, c.part, c.variant)).collect();
+
+        bail!("slower than the baseline: {}", names.join(", "));
+    }
+
+    Ok(())
+}
+
+/// A random input for a day, always the same one for the same seed and size
+pub fn generate(day: &Day, seed: u64, size: usize) -> anyhow::Result<String> {
+    if size > day.max_size {
+        bail!("day {} can't make an input bigger than {}, not {size}", day.day, day.max_size);
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    Ok((day.generate)(&mut rng, size))
+}
+
+/// Records a day's simulation and writes the frames out
+pub fn record(day: &Day, input: Option<&Path>, out: &Path, export: Export) -> anyhow::Result<()> {
+    let input = input::load(day.day, input)?;
+    let mut recorder = Recorder::new();
+
+    (day.record)(&input, &mut recorder).with_context(|| format!("day {}", day.day))?;
+
+    if recorder.is_empty() {
+        bail!("day {} isn't a simulation, there's nothing to record", day.day);
+    }
+
+    let written = recorder.export(out, export)?;
+
+    match written.as_slice() {
+        [file] => println!("day {}: {} frames in {}", day.day, recorder.len(), file.display()),
+        _ => println!("day {}: {} frames in {}/", day.day, written.len(), out.display()),
+    }
+
+    Ok(())
+}
+
+pub fn list() {
+    for day in DAYS {
+        println!("{:>2}  {}", day.day, day.name);
+
+        for part in Part::ALL {
+            let variants = (day.variants)(part);
+
+            if !variants.is_empty() {
+                println!("      part {part}: {}", variants.join(", "));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_in_order() {
+        assert!(DAYS.windows(2).all(|w| w[0].day + 1 == w[1].day));
+    }
+
+    #[test]
+    fn generated_inputs_parse() {
+        for day in DAYS {
+            for seed in 0..3 {
+                let input = generate(day, seed, 12).unwrap();
+
+                assert_eq!(input, generate(day, seed, 12).unwrap(), "day {} isn't deterministic", day.day);
+
+                if let Err(err) = (day.check)(&input) {
+                    panic!("day {} seed {seed} made an input it can't parse: {err:#}\n{input}", day.day);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn oversized_inputs() {
+        let day = find_day(9).unwrap();
+        let err = generate(day, 0, day.max_size + 1).unwrap_err();
+
+        assert!(err.to_string().starts_with("day 9 can't make an input bigger than"));
+    }
+
+    #[test]
+    fn bad_selections() {
+        assert_eq!("all".parse::<Selection>().unwrap(), Selection::All);
+        assert_eq!("8".parse::<Selection>().unwrap(), Selection::Day(8));
+        assert!((DAYS.last().unwrap().day + 1).to_string().parse::<Selection>().is_err());
+        assert!("eight".parse::<Selection>().is_err());
+        assert!("3".parse::<Part>().is_err());
+    }
+}
@@ -0,0 +1,157 @@
+use std::{fs::{self, OpenOptions}, io::Write as _, path::{Path, PathBuf}};
+
+use anyhow::{Context as _, bail};
+
+/// What every new day starts out as, with `Template` standing in for the day's type and `day0` for its number
+const TEMPLATE: &str = include_str!("day0.rs");
+
+/// What every binary starts with, see `allocations::Allocator`
+const BIN_PRELUDE: &str = "use aoc2025::allocations::Allocator;\n\n#[global_allocator]\nstatic ALLOCATOR: Allocator = Allocator::new();\n\n";
+
+/// Adds day `day` to the crate at `root`: its module from the template, its binary, an empty input to paste
+/// the real one into, an empty example with the answers it should give, and its entries in the module list,
+/// the runner's list of days and the answers
+///
+/// Nothing that's already there gets overwritten, and days go in order. Everything is worked out before
+/// anything is written, so a crate that doesn't look the way it's expected to is left alone. Returns the
+/// files that were created or changed, relative to `root`.
+pub fn new_day(root: &Path, day: u8, name: &str) -> anyhow::Result<Vec<PathBuf>> {
+    if !name.starts_with(|c: char| c.is_ascii_uppercase()) || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+        bail!("{name:?} won't do as a type name, it should look like BullshitPacking");
+    }
+
+    let module = format!("src/day{day}.rs");
+    let binary = format!("src/bin/day{day}.rs");
+    let input = format!("src/inputs/day{day}.txt");
+    let example = format!("examples/day{day}/example.txt");
+    let example_answers = format!("examples/day{day}/example.answers");
+
+    for path in [&module, &binary, &input, &example, &example_answers] {
+        if root.join(path).exists() {
+            bail!("day {day} is already there ({path}), not overwriting it");
+        }
+    }
+
+    let lib = read(root, "src/lib.rs")?;
+    let runner = read(root, "src/runner.rs")?;
+    let answers = read(root, "src/answers.txt")?;
+
+    let last = lib.lines()
+        .filter_map(|line| line.strip_prefix("pub mod day")?.strip_suffix(';')?.parse::<u8>().ok())
+        .max()
+        .unwrap_or(0);
+
+    if day != last + 1 {
+        bail!("days go in order, the next one is day {}", last + 1);
+    }
+
+    let lib = replace_once(&lib, &format!("pub mod day{last};\n"), &format!("pub mod day{last};\npub mod day{day};\n"))
+        .context("src/lib.rs doesn't have the days' modules where they're expected")?;
+
+    let entry = format!("Day::new::<day{day}::{name}>({day}, \"{name}\"),");
+    let runner = replace_once(&runner, &format!("day{last}}};"), &format!("day{last}, day{day}}};"))
+        .and_then(|runner| {
+            let previous = runner.find(&format!("Day::new::<day{last}::"))?;
+            let end_of_line = previous + runner[previous..].find('\n')?;
+
+            Some(format!("{}\n    {entry}{}", &runner[..end_of_line], &runner[end_of_line..]))
+        })
+        .context("src/runner.rs doesn't import and list the days the way it's expected to")?;
+
+    let newline = if answers.is_empty() || answers.ends_with('\n') { "" } else { "\n" };
+    let answers = format!("{answers}{newline}{day} 1 ?\n{day} 2 ?\n");
+
+    let code = TEMPLATE.replace("Template", name).replace("day0", &format!("day{day}"));
+    let main = format!("{BIN_PRELUDE}fn main() -> std::process::ExitCode {{\n    aoc2025::cli::day_main({day})\n}}\n");
+
+    create(root, &module, &code)?;
+    create(root, &binary, &main)?;
+    create(root, &input, "")?;
+    create(root, &example, "")?;
+    create(root, &example_answers, "# What the example comes out as, a part and its answer per line, like:\n# 1 42\n")?;
+
+    for (path, contents) in [("src/lib.rs", lib), ("src/runner.rs", runner), ("src/answers.txt", answers)] {
+        fs::write(root.join(path), contents).with_context(|| format!("couldn't update {path}"))?;
+    }
+
+    Ok([&module, &binary, &input, &example, &example_answers, "src/lib.rs", "src/runner.rs", "src/answers.txt"].map(PathBuf::from).into())
+}
+
+fn read(root: &Path, path: &str) -> anyhow::Result<String> {
+    fs::read_to_string(root.join(path)).with_context(|| format!("couldn't read {path}"))
+}
+
+/// Only if `from` appears exactly once
+fn replace_once(text: &str, from: &str, to: &str) -> Option<String> {
+    (text.matches(from).count() == 1).then(|| text.replacen(from, to, 1))
+}
+
+fn create(root: &Path, path: &str, contents: &str) -> anyhow::Result<()> {
+    let full = root.join(path);
+
+    if let Some(dir) = full.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("couldn't make a place for {path}"))?;
+    }
+
+    // Checked before, but not overwriting is the one thing this must get right
+    OpenOptions::new().write(true).create_new(true).open(&full)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .with_context(|| format!("couldn't create {path}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A copy of the files that get changed, so the real ones don't
+    fn scratch_crate() -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+
+        fs::write(root.join("src/lib.rs"), include_str!("lib.rs")).unwrap();
+        fs::write(root.join("src/runner.rs"), include_str!("runner.rs")).unwrap();
+        fs::write(root.join("src/answers.txt"), include_str!("answers.txt")).unwrap();
+
+        root
+    }
+
+    #[test]
+    fn new_days() {
+        let root = scratch_crate();
+        let next = crate::runner::DAYS.last().unwrap().day + 1;
+
+        assert!(new_day(&root, next, "lowercase").is_err());
+        assert!(new_day(&root, next + 1, "Skipped").is_err());
+
+        let changed = new_day(&root, next, "Snowfall").unwrap();
+        assert_eq!(changed.len(), 8);
+
+        let module = fs::read_to_string(root.join(format!("src/day{next}.rs"))).unwrap();
+        assert!(module.contains("impl Solution for Snowfall"));
+        assert!(module.contains(&format!("include_str!(\"../examples/day{next}/example.txt\")")));
+        assert!(!module.contains("Template") && !module.contains("day0"));
+
+        // Nothing to check yet, but it's a fixture like the others
+        let answers = fs::read_to_string(root.join(format!("examples/day{next}/example.answers"))).unwrap();
+        assert!(crate::fixtures::parse_answers(&answers).unwrap().is_empty());
+        assert!(root.join(format!("examples/day{next}/example.txt")).exists());
+
+        assert!(fs::read_to_string(root.join("src/lib.rs")).unwrap().contains(&format!("pub mod day{next};\n")));
+        assert!(fs::read_to_string(root.join("src/answers.txt")).unwrap().ends_with(&format!("{next} 1 ?\n{next} 2 ?\n")));
+
+        let runner = fs::read_to_string(root.join("src/runner.rs")).unwrap();
+        assert!(runner.contains(&format!("Day::new::<day{next}::Snowfall>({next}, \"Snowfall\"),\n];")));
+        assert!(runner.contains(&format!(", day{next}}};")));
+
+        // Already there
+        let err = new_day(&root, next, "Snowfall").unwrap_err();
+        assert!(err.to_string().contains("not overwriting"));
+        assert_eq!(fs::read_to_string(root.join(format!("src/day{next}.rs"))).unwrap(), module);
+
+        assert!(new_day(&root, next + 1, "Sleigh").is_ok());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,255 @@
+use std::fmt::Display;
+
+use anyhow::{Context as _, bail};
+use rand::rngs::StdRng;
+
+use crate::{deadline, recorder::Recorder, runner::Part};
+
+/// A day's puzzle: parse the input once, then answer either part from it
+///
+/// Parts take `&self` so both can be asked of the same parse; solvers that
+/// simulate in place work on a copy.
+pub trait Solution: Sized + 'static {
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> anyhow::Result<Self>;
+
+    /// Fails when the input parses fine but has no answer this part can give, like one that doesn't fit its type
+    fn part1(&self) -> anyhow::Result<Self::Part1>;
+
+    fn part2(&self) -> anyhow::Result<Self::Part2>;
+
+    /// A random but valid input with about `size` of whatever the day is made of (lines, machines, regions...)
+    fn generate(rng: &mut StdRng, size: usize) -> String;
+
+    /// The biggest `size` that `generate` can make an input of
+    const MAX_SIZE: usize = usize::MAX;
+
+    /// What the variants give one answer for, e.g. "bank" or "machine"
+    const ITEM: &'static str = "item";
+
+    /// Every way this part can be solved, for `--cross-check`
+    fn variants(part: Part) -> &'static [Variant<Self>] {
+        let _ = part;
+
+        &[]
+    }
+
+    /// Plays the simulation into `recorder` a frame per step, for the days that are one
+    fn record(&self, recorder: &mut Recorder) {
+        let _ = recorder;
+    }
+
+    /// A line per step of the way to the part's answer, for finding where it went wrong
+    fn trace(&self, part: Part) -> Vec<String> {
+        let _ = part;
+
+        vec![]
+    }
+}
+
+/// What the part's own solver is called, next to the named variants
+pub const DEFAULT: &str = "default";
+
+/// One of the ways to solve a part, answering for each item of the input separately
+/// so disagreements can be pinned down; the items add up to the part's answer
+pub struct Variant<S> {
+    pub name: &'static str,
+    pub items: fn(&S) -> Vec<u64>,
+}
+
+/// Where the variants of a part didn't see eye to eye
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrossCheck {
+    pub item: &'static str,
+    /// How many items the variants went through
+    pub items: usize,
+    pub variants: Vec<&'static str>,
+    pub disagreements: Vec<Disagreement>,
+    /// Items some variant gave up on, which aren't compared
+    pub unresolved: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    /// 0-based, in input order
+    pub index: usize,
+    /// The answer for that item from each variant, in the same order as `CrossCheck::variants`
+    pub answers: Vec<Option<u64>>,
+}
+
+/// The last day only has one puzzle, the second star comes for free
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Freebie;
+
+impl Display for Freebie {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("free")
+    }
+}
+
+/// Parses and solves one part, with the answer type erased for the runner
+///
+/// A named variant answers instead of the part's own solver if there's one in `variant`.
+pub fn answer<S: Solution>(part: Part, input: &str, variant: Option<&str>) -> anyhow::Result<String> {
+    let variant = match variant {
+        Some(name) if name != DEFAULT => Some(find_variant::<S>(part, name)?),
+        _ => None,
+    };
+
+    let solution = S::parse(input).context("malformed input")?;
+
+    Ok(match (part, variant) {
+        (_, Some(variant)) => (variant.items)(&solution).iter().sum::<u64>().to_string(),
+        (Part::One, None) => solution.part1()?.to_string(),
+        (Part::Two, None) => solution.part2()?.to_string(),
+    })
+}
+
+/// Just parses, to see whether an input would be accepted
+pub fn check<S: Solution>(input: &str) -> anyhow::Result<()> {
+    S::parse(input).context("malformed input").map(|_| ())
+}
+
+/// Parses and records the simulation, with the day's type erased for the runner
+pub fn record<S: Solution>(input: &str, recorder: &mut Recorder) -> anyhow::Result<()> {
+    S::parse(input).context("malformed input")?.record(recorder);
+
+    Ok(())
+}
+
+/// Parses and traces a part, with the day's type erased for the runner
+pub fn trace<S: Solution>(part: Part, input: &str) -> anyhow::Result<Vec<String>> {
+    Ok(S::parse(input).context("malformed input")?.trace(part))
+}
+
+pub fn variant_names<S: Solution>(part: Part) -> Vec<&'static str> {
+    S::variants(part).iter().map(|v| v.name).collect()
+}
+
+pub fn find_variant<S: Solution>(part: Part, name: &str) -> anyhow::Result<&'static Variant<S>> {
+    match S::variants(part).iter().find(|v| v.name == name) {
+        Some(variant) => Ok(variant),
+        None => bail!("part {part} has no variant {name:?}, it has {:?}", variant_names::<S>(part)),
+    }
+}
+
+/// Runs the variants of a part whose names are in `only` (all of them if it's empty) and compares them item by item
+pub fn cross_check<S: Solution>(part: Part, input: &str, only: &[String]) -> anyhow::Result<CrossCheck> {
+    let solution = S::parse(input).context("malformed input")?;
+
+    for name in only {
+        find_variant::<S>(part, name)?;
+    }
+
+    let variants: Vec<_> = S::variants(part).iter()
+        .filter(|v| only.is_empty() || only.iter().any(|name| name == v.name))
+        .collect();
+
+    let mut unresolved = vec![];
+    let answers: Vec<_> = variants.iter().map(|v| {
+        let (items, gave_up) = deadline::capture_unresolved(|| (v.items)(&solution));
+
+        unresolved.extend(gave_up);
+
+        items
+    }).collect();
+    let item_count = answers.iter().map(Vec::len).max().unwrap_or(0);
+
+    unresolved.sort();
+    unresolved.dedup();
+
+    let disagreements = (0..item_count)
+        .map(|index| Disagreement {
+            index,
+            answers: answers.iter().map(|items| items.get(index).copied()).collect(),
+        })
+        .filter(|d| !unresolved.contains(&d.index) && d.answers.windows(2).any(|w| w[0] != w[1]))
+        .collect();
+
+    Ok(CrossCheck {
+        item: S::ITEM,
+        items: item_count,
+        variants: variants.iter().map(|v| v.name).collect(),
+        disagreements,
+        unresolved,
+    })
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, answer) in self.answers.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" vs ")?;
+            }
+
+            match answer {
+                Some(answer) => write!(f, "{answer}")?,
+                None => f.write_str("nothing")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Doubler(Vec<u64>);
+
+    impl Solution for Doubler {
+        type Part1 = u64;
+        type Part2 = u64;
+
+        const ITEM: &'static str = "number";
+
+        fn parse(input: &str) -> anyhow::Result<Self> {
+            Ok(Self(input.split(',').map(str::parse).collect::<Result<_, _>>()?))
+        }
+
+        fn part1(&self) -> anyhow::Result<u64> {
+            Ok(self.0.iter().map(|n| n * 2).sum())
+        }
+
+        fn part2(&self) -> anyhow::Result<u64> {
+            Ok(0)
+        }
+
+        fn generate(_: &mut StdRng, size: usize) -> String {
+            vec!["1"; size].join(",")
+        }
+
+        fn variants(part: Part) -> &'static [Variant<Self>] {
+            match part {
+                Part::One => &[
+                    Variant { name: "times", items: |d| d.0.iter().map(|n| n * 2).collect() },
+                    Variant { name: "plus", items: |d| d.0.iter().map(|n| n + n).collect() },
+                    Variant { name: "shift", items: |d| d.0.iter().map(|n| n << (n % 3)).collect() },
+                ],
+                Part::Two => &[],
+            }
+        }
+    }
+
+    #[test]
+    fn disagreements_per_item() {
+        let check = cross_check::<Doubler>(Part::One, "1,3,4,5", &[]).unwrap();
+
+        assert_eq!((check.item, check.items), ("number", 4));
+        assert_eq!(check.variants, ["times", "plus", "shift"]);
+        assert_eq!(check.disagreements, [
+            Disagreement { index: 1, answers: vec![Some(6), Some(6), Some(3)] },
+            Disagreement { index: 3, answers: vec![Some(10), Some(10), Some(20)] },
+        ]);
+        assert_eq!(check.disagreements[0].to_string(), "6 vs 6 vs 3");
+
+        let check = cross_check::<Doubler>(Part::One, "1,3,4,5", &["times".into(), "plus".into()]).unwrap();
+
+        assert!(check.disagreements.is_empty());
+        assert!(cross_check::<Doubler>(Part::One, "1", &["divide".into()]).is_err());
+        assert!(cross_check::<Doubler>(Part::Two, "1", &[]).unwrap().variants.is_empty());
+    }
+}
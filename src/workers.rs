@@ -0,0 +1,161 @@
+use std::sync::{Condvar, Mutex, atomic::{AtomicU64, Ordering}};
+
+/// What the heavy models get between them unless `--memory` or `AOC_MEMORY` say otherwise, about what
+/// day 12 could be spared on the machine it was first solved on
+pub const DEFAULT_MEMORY: u64 = 20 << 30;
+
+static BUDGET: Budget = Budget::new(DEFAULT_MEMORY);
+
+/// Sets up rayon's pool and the memory budget, from the flags if given, otherwise from `AOC_THREADS` and
+/// `AOC_MEMORY`, otherwise one thread per core and [`DEFAULT_MEMORY`]
+pub fn init(threads: Option<usize>, memory: Option<u64>) {
+    let threads = threads.or_else(|| from_env("AOC_THREADS", |threads| match threads.parse::<usize>() {
+        Ok(0) => Err("needs at least one thread".to_string()),
+        parsed => parsed.map_err(|err| err.to_string()),
+    }));
+
+    if let Some(threads) = threads
+        && let Err(err) = rayon::ThreadPoolBuilder::new().num_threads(threads).build_global()
+    {
+        log::warn!("couldn't set up {threads} worker threads: {err}");
+    }
+
+    if let Some(memory) = memory.or_else(|| from_env("AOC_MEMORY", parse_bytes)) {
+        BUDGET.set_total(memory);
+    }
+}
+
+fn from_env<T>(name: &str, parse: impl FnOnce(&str) -> Result<T, String>) -> Option<T> {
+    let value = std::env::var(name).ok()?;
+
+    parse(&value).inspect_err(|err| log::warn!("ignoring {name}={value:?}: {err}")).ok()
+}
+
+/// A byte count like `1073741824`, `512M` or `16GiB`; the suffixes go in powers of 1024
+pub fn parse_bytes(text: &str) -> Result<u64, String> {
+    let text = text.trim();
+    let digits = text.find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len());
+    let (number, unit) = text.split_at(digits);
+
+    let number: u64 = number.parse().map_err(|_| format!("{text:?} doesn't start with a number"))?;
+
+    let shift = match unit.trim().to_ascii_lowercase().trim_end_matches('b').trim_end_matches('i') {
+        "" => 0,
+        "k" => 10,
+        "m" => 20,
+        "g" => 30,
+        "t" => 40,
+        _ => return Err(format!("unknown unit {unit:?}, use K, M, G or T")),
+    };
+
+    number.checked_mul(1 << shift).ok_or_else(|| format!("{text} is more bytes than anyone has"))
+}
+
+/// Holds off starting a heavy model until it fits in the memory budget next to the ones already running
+///
+/// `bytes` is a guess, going by however the model is usually sized. One that's bigger than the whole budget
+/// still gets to run, just on its own.
+pub fn reserve(bytes: u64) -> Reservation<'static> {
+    BUDGET.reserve(bytes)
+}
+
+/// How much memory the models running at once may take, shared by every thread
+pub struct Budget {
+    total: AtomicU64,
+    in_use: Mutex<u64>,
+    freed: Condvar,
+}
+
+/// A model's share of the budget, given back on drop
+pub struct Reservation<'a> {
+    budget: &'a Budget,
+    bytes: u64,
+}
+
+impl Budget {
+    pub const fn new(total: u64) -> Self {
+        Self { total: AtomicU64::new(total), in_use: Mutex::new(0), freed: Condvar::new() }
+    }
+
+    pub fn set_total(&self, total: u64) {
+        self.total.store(total, Ordering::Relaxed);
+        self.freed.notify_all();
+    }
+
+    pub fn in_use(&self) -> u64 {
+        *self.in_use.lock().unwrap()
+    }
+
+    pub fn reserve(&self, bytes: u64) -> Reservation<'_> {
+        let mut in_use = self.in_use.lock().unwrap();
+
+        if *in_use > 0 && in_use.saturating_add(bytes) > self.total.load(Ordering::Relaxed) {
+            log::debug!("waiting for {} MiB of memory, {} MiB are taken", bytes >> 20, *in_use >> 20);
+        }
+
+        in_use = self.freed.wait_while(in_use, |in_use| {
+            *in_use > 0 && in_use.saturating_add(bytes) > self.total.load(Ordering::Relaxed)
+        }).unwrap();
+
+        *in_use += bytes;
+
+        Reservation { budget: self, bytes }
+    }
+}
+
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        *self.budget.in_use.lock().unwrap() -= self.bytes;
+        self.budget.freed.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::mpsc, time::Duration};
+
+    use super::*;
+
+    #[test]
+    fn byte_counts() {
+        assert_eq!(parse_bytes("1500"), Ok(1500));
+        assert_eq!(parse_bytes("512M"), Ok(512 << 20));
+        assert_eq!(parse_bytes("16GiB"), Ok(16 << 30));
+        assert_eq!(parse_bytes("2 kb"), Ok(2048));
+        assert!(parse_bytes("lots").is_err());
+        assert!(parse_bytes("3 parsecs").is_err());
+        assert!(parse_bytes("99999999999T").is_err());
+    }
+
+    #[test]
+    fn models_wait_for_room() {
+        let budget = Budget::new(10);
+        let (sender, receiver) = mpsc::channel();
+
+        std::thread::scope(|scope| {
+            let first = budget.reserve(6);
+            let second = budget.reserve(4);
+
+            scope.spawn(|| {
+                let _third = budget.reserve(3);
+
+                sender.send(budget.in_use()).unwrap();
+            });
+
+            // Full up, the third one has to wait
+            assert!(receiver.recv_timeout(Duration::from_millis(100)).is_err());
+
+            drop(second);
+
+            assert_eq!(receiver.recv_timeout(Duration::from_secs(10)), Ok(9));
+
+            drop(first);
+        });
+
+        assert_eq!(budget.in_use(), 0);
+
+        // Too big for the budget, but nothing else is running
+        let _huge = budget.reserve(100);
+        assert_eq!(budget.in_use(), 100);
+    }
+}